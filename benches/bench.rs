@@ -29,6 +29,7 @@ fn criterion_benchmark(c: &mut Criterion) {
         ("chinese yyyy mm dd hh mm ss", "2014年04月08日11时25分18秒"),
         ("chinese yyyy mm dd", "2014年04月08日"),
         ("timezone_abbrev", "2017-11-25 13:31:15 PST"),
+        ("common log format", "[10/Oct/2000:13:55:36 -0700]"),
     ] {
         group.bench_function(*name, |b| {
             b.iter(|| {
@@ -37,6 +38,21 @@ fn criterion_benchmark(c: &mut Criterion) {
         });
     }
     group.finish();
+
+    // the token following the time is checked as a timezone abbreviation on every line
+    let mut group = c.benchmark_group("parse_prefix");
+    for (name, input) in &[
+        ("yyyy-mm-dd hh:mm:ss", "2021-11-08 00:32:45 INFO starting"),
+        ("rfc3339", "2021-11-08T00:32:45.426Z INFO starting"),
+        ("timezone_abbrev", "2021-11-08 00:32:45 PST INFO starting"),
+    ] {
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                let _res = anydate::datetime::parse_prefix(input);
+            });
+        });
+    }
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,181 @@
+//! ASN.1 time parsing functions
+//!
+//! Implements the `UTCTime` and `GeneralizedTime` types used by X.509 certificates, CRLs and OCSP
+//! responses eg. `211108003245Z` and `20211108003245.426Z`, including the optional seconds and
+//! offsets allowed outside of DER.
+use crate::datetime::parse_numeric_offset;
+use crate::errors::Error;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
+
+/// Attempts to parse the provided `GeneralizedTime` or `UTCTime` string into a
+/// `DateTime`\<`FixedOffset`\>.
+///
+/// Strings that are valid as either type are parsed as the DER encoding, which always includes
+/// the seconds eg. `210101010101Z` is a `UTCTime` rather than a `GeneralizedTime` without seconds.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported time is provided.
+pub fn parse(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    match s.len() {
+        13 | 17 => parse_utc_time(s).or_else(|_| parse_generalized_time(s)),
+        _ => parse_generalized_time(s).or_else(|_| parse_utc_time(s)),
+    }
+}
+
+/// Attempts to parse the provided `UTCTime` eg. `211108003245Z` into a
+/// `DateTime`\<`FixedOffset`\>.
+///
+/// As required by RFC 5280, two digit years of `50` or more are in the 1900s and otherwise in the
+/// 2000s.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported time is provided.
+pub fn parse_utc_time(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let b = s.as_bytes();
+    let (year, idx) = digits(b, 0, 2).ok_or(Error::InvalidDateTime)?;
+    let year = if year >= 50 { 1900 + year } else { 2000 + year };
+    let (date, idx) = parse_month_day(b, idx, year).ok_or(Error::InvalidDateTime)?;
+    let (hour, idx) = digits(b, idx, 2).ok_or(Error::InvalidDateTime)?;
+    let (minute, idx) = digits(b, idx, 2).ok_or(Error::InvalidDateTime)?;
+    let (second, idx) = digits(b, idx, 2).unwrap_or((0, idx));
+    let time = NaiveTime::from_hms_opt(hour, minute, second).ok_or(Error::InvalidDateTime)?;
+    // a zone is required
+    match s.get(idx..) {
+        Some("") | None => Err(Error::InvalidDateTime),
+        Some(tz) => parse_zone(tz)?
+            .from_local_datetime(&date.and_time(time))
+            .single()
+            .ok_or(Error::InvalidDateTime),
+    }
+}
+
+/// Attempts to parse the provided `GeneralizedTime` eg. `20211108003245.426Z` into a
+/// `DateTime`\<`FixedOffset`\>.
+///
+/// The minutes, seconds and fraction of a second are optional, and times without a zone are
+/// assumed to be in UTC.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported time is provided.
+pub fn parse_generalized_time(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let b = s.as_bytes();
+    let (year, idx) = digits(b, 0, 4).ok_or(Error::InvalidDateTime)?;
+    let (date, idx) = parse_month_day(b, idx, year).ok_or(Error::InvalidDateTime)?;
+    let (hour, idx) = digits(b, idx, 2).ok_or(Error::InvalidDateTime)?;
+    let (minute, idx) = digits(b, idx, 2).unwrap_or((0, idx));
+    let (second, mut idx) = digits(b, idx, 2).unwrap_or((0, idx));
+    let mut nanos = 0;
+    if idx == 14 && matches!(b.get(idx), Some(b'.' | b',')) {
+        let start = idx + 1;
+        idx = start + b[start..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if idx == start {
+            return Err(Error::InvalidDateTime);
+        }
+        // truncate to nanoseconds
+        nanos = (start..start + 9).fold(0, |nanos, i| {
+            nanos * 10
+                + b.get(i)
+                    .filter(|_| i < idx)
+                    .map_or(0, |d| u32::from(d - b'0'))
+        });
+    }
+    let time =
+        NaiveTime::from_hms_nano_opt(hour, minute, second, nanos).ok_or(Error::InvalidDateTime)?;
+    let offset = match &s[idx..] {
+        "" => Utc.fix(),
+        tz => parse_zone(tz)?,
+    };
+    offset
+        .from_local_datetime(&date.and_time(time))
+        .single()
+        .ok_or(Error::InvalidDateTime)
+}
+
+fn parse_month_day(b: &[u8], idx: usize, year: u32) -> Option<(NaiveDate, usize)> {
+    let (month, idx) = digits(b, idx, 2)?;
+    let (day, idx) = digits(b, idx, 2)?;
+    let date = NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)?;
+    Some((date, idx))
+}
+
+// `Z` or a `+hhmm` offset
+fn parse_zone(tz: &str) -> Result<FixedOffset, Error> {
+    match tz.len() {
+        1 if tz == "Z" => Ok(Utc.fix()),
+        5 => parse_numeric_offset(tz),
+        _ => Err(Error::InvalidDateTime),
+    }
+}
+
+// parses exactly `n` ASCII digits starting at `idx`, returning the value and the index after.
+fn digits(b: &[u8], idx: usize, n: usize) -> Option<(u32, usize)> {
+    let value = b.get(idx..idx + n)?.iter().try_fold(0, |value, d| {
+        d.is_ascii_digit().then(|| value * 10 + u32::from(d - b'0'))
+    })?;
+    Some((value, idx + n))
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utc_time() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("211108003245Z", 1636331565),
+            ("2111080032Z", 1636331520),
+            ("211108013245+0100", 1636331565),
+            ("491231235959Z", 2524607999),
+            ("500101000000Z", -631152000),
+        ] {
+            assert_eq!(*expected, parse_utc_time(input)?.timestamp(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generalized_time() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("20211108003245Z", 1636331565000000000),
+            ("20211108003245.426Z", 1636331565426000000),
+            ("20211108003245,426Z", 1636331565426000000),
+            ("20211108013245.426+0100", 1636331565426000000),
+            ("20211108003245", 1636331565000000000),
+            ("2021110800Z", 1636329600000000000),
+            ("202111080032Z", 1636331520000000000),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_generalized_time(input)?
+                    .timestamp_nanos_opt()
+                    .unwrap(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn ambiguous() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(1609462861, parse("210101010101Z")?.timestamp());
+        assert_eq!(1609462861, parse("210101020101+0100")?.timestamp());
+        assert_eq!(4133984460, parse("210101010101")?.timestamp());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "211108003245",
+            "211108003245+01",
+            "211308003245Z",
+            "20211108003245.Z",
+            "202111080032.5Z",
+            "20211108003245Zjunk",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
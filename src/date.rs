@@ -1,5 +1,6 @@
 //! Date parsing functions
 use crate::errors::Error;
+use crate::format::Format;
 use chrono::NaiveDate;
 
 /// Attempts to parse the provided string into a `NaiveDate`.
@@ -8,10 +9,20 @@ use chrono::NaiveDate;
 /// Will return `Err` when an invalid or unsupported `Date` format is provided.
 #[inline]
 pub fn parse(s: &str) -> Result<NaiveDate, Error> {
-    match s.get(..1) {
+    parse_with_format(s).map(|(date, _)| date)
+}
+
+/// Attempts to parse the provided string into a `NaiveDate`, also returning the [`Format`] it was
+/// recognized as.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `Date` format is provided.
+pub fn parse_with_format(s: &str) -> Result<(NaiveDate, Format), Error> {
+    // the first character may be multi-byte eg. `令和3年11月8日`
+    match s.as_bytes().first() {
         None => Err(Error::InvalidDate),
         Some(c) => {
-            if c.as_bytes()[0].is_ascii_digit() {
+            if c.is_ascii_digit() {
                 parse_unknown_alpha(s)
             } else {
                 parse_with_alpha(s)
@@ -20,15 +31,17 @@ pub fn parse(s: &str) -> Result<NaiveDate, Error> {
     }
 }
 
-pub(crate) fn parse_unknown_alpha(s: &str) -> Result<NaiveDate, Error> {
+pub(crate) fn parse_unknown_alpha(s: &str) -> Result<(NaiveDate, Format), Error> {
     parse_naive_dates(s)
+        .or_else(|_| crate::japanese::parse_date(s).map(|date| (date, Format::Japanese)))
 }
 
-pub(crate) fn parse_with_alpha(s: &str) -> Result<NaiveDate, Error> {
+pub(crate) fn parse_with_alpha(s: &str) -> Result<(NaiveDate, Format), Error> {
     parse_naive_dates_replace(s)
+        .or_else(|_| crate::japanese::parse_date(s).map(|date| (date, Format::Japanese)))
 }
 
-fn parse_naive_dates(s: &str) -> Result<NaiveDate, Error> {
+fn parse_naive_dates(s: &str) -> Result<(NaiveDate, Format), Error> {
     // Date parse formats
     const PARSE_FORMATS: &[&str] = &[
         "%Y-%m-%d",
@@ -45,12 +58,12 @@ fn parse_naive_dates(s: &str) -> Result<NaiveDate, Error> {
     ];
     PARSE_FORMATS
         .iter()
-        .map(|fmt| NaiveDate::parse_from_str(s, fmt))
+        .map(|fmt| NaiveDate::parse_from_str(s, fmt).map(|date| (date, Format::Strftime(fmt))))
         .find_map(Result::ok)
         .map_or_else(|| Err(Error::InvalidDate), Ok)
 }
 
-fn parse_naive_dates_replace(s: &str) -> Result<NaiveDate, Error> {
+fn parse_naive_dates_replace(s: &str) -> Result<(NaiveDate, Format), Error> {
     // Date parse formats
     const PARSE_FORMATS: &[&str] = &[
         "%B %d %y",
@@ -63,7 +76,7 @@ fn parse_naive_dates_replace(s: &str) -> Result<NaiveDate, Error> {
     let s = s.replace([',', '.'], "");
     PARSE_FORMATS
         .iter()
-        .map(|fmt| NaiveDate::parse_from_str(&s, fmt))
+        .map(|fmt| NaiveDate::parse_from_str(&s, fmt).map(|date| (date, Format::Strftime(fmt))))
         .find_map(Result::ok)
         .map_or_else(|| Err(Error::InvalidDate), Ok)
 }
@@ -109,6 +122,10 @@ mod tests {
             ("2014.03.30", 1396137600000000000),
             // chinese yyyy mm dd
             ("2014年04月08日", 1396915200000000000),
+            // japanese
+            ("2014年4月8日(火)", 1396915200000000000),
+            ("平成26年4月8日", 1396915200000000000),
+            ("H26.4.8", 1396915200000000000),
         ] {
             assert_eq!(
                 *expected,
@@ -1,6 +1,8 @@
 //! `DateTime` parsing functions
 use crate::errors::Error;
+use crate::format::Format;
 use chrono::{DateTime, FixedOffset, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+use std::borrow::Cow;
 
 /// Attempts to parse the provided string into a `DateTime`\<`FixedOffset`\>.
 /// Also see [`parse_utc`] for a convenience conversion to `DateTime`\<`Utc`\>.
@@ -9,10 +11,21 @@ use chrono::{DateTime, FixedOffset, NaiveDateTime, NaiveTime, Offset, TimeZone,
 /// Will return `Err` when an invalid or unsupported `DateTime` format is provided.
 #[inline]
 pub fn parse(s: &str) -> Result<DateTime<FixedOffset>, Error> {
-    match s.get(..1) {
+    parse_with_format(s).map(|(dt, _)| dt)
+}
+
+/// Attempts to parse the provided string into a `DateTime`\<`FixedOffset`\>, also returning the
+/// [`Format`] it was recognized as.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `DateTime` format is provided.
+pub fn parse_with_format(s: &str) -> Result<(DateTime<FixedOffset>, Format), Error> {
+    let s = &*normalize_fraction(s);
+    // the first character may be multi-byte eg. `令和3年11月8日`
+    match s.as_bytes().first() {
         None => Err(Error::InvalidDateTime),
         Some(c) => {
-            if c.as_bytes()[0].is_ascii_digit() {
+            if c.is_ascii_digit() {
                 parse_unknown_alpha(s)
             } else {
                 parse_with_alpha(s)
@@ -32,40 +45,175 @@ pub fn parse_utc(s: &str) -> Result<DateTime<Utc>, Error> {
     Ok(fdt.with_timezone(&Utc))
 }
 
-fn parse_unknown_alpha(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+/// Attempts to parse the longest leading `DateTime` of the provided string, returning it along
+/// with the remainder of the string following it, eg. to split a timestamp off of a log line.
+///
+/// A leading timestamp wrapped in brackets, eg. `[2021-11-08 00:32:45] message`, is also
+/// supported in which case the remainder follows the closing bracket.
+///
+/// # Errors
+/// Will return `Err` when the string does not start with a supported `DateTime` format.
+pub fn parse_prefix(s: &str) -> Result<(DateTime<FixedOffset>, &str), Error> {
+    if let Some(inner) = s.strip_prefix('[') {
+        let end = inner.find(']').ok_or(Error::InvalidDateTime)?;
+        return Ok((parse(inner[..end].trim())?, &inner[end + 1..]));
+    }
+    if let Some(end) = iso_prefix_len(s) {
+        if let Ok(dt) = parse(&s[..end]) {
+            return Ok((dt, &s[end..]));
+        }
+    }
+    // only consider prefixes ending at whitespace, checking the longest first
+    let max = (0..=s.len().min(MAX_PREFIX_LEN))
+        .rev()
+        .find(|idx| s.is_char_boundary(*idx))
+        .unwrap_or_default();
+    (max == s.len())
+        .then_some(max)
+        .into_iter()
+        .chain(
+            s[..max]
+                .char_indices()
+                .rev()
+                .filter(|(_, c)| c.is_whitespace())
+                .map(|(idx, _)| idx),
+        )
+        .find_map(|end| parse(&s[..end]).ok().map(|dt| (dt, &s[end..])))
+        .ok_or(Error::InvalidDateTime)
+}
+
+// longest prefix considered by `parse_prefix`, long enough for the most verbose formats.
+const MAX_PREFIX_LEN: usize = 96;
+
+// Returns the length of a leading `yyyy-mm-dd[T ]hh:mm[:ss[.f]][zone]` timestamp if it's
+// unambiguously complete, allowing the common RFC 3339 and `yyyy-mm-dd hh:mm:ss` log prefixes
+// to be parsed without checking every possible prefix length.
+fn iso_prefix_len(s: &str) -> Option<usize> {
+    fn digits(b: &[u8], idx: usize, n: usize) -> Option<usize> {
+        b.get(idx..idx + n)?
+            .iter()
+            .all(u8::is_ascii_digit)
+            .then_some(idx + n)
+    }
+    fn byte(b: &[u8], idx: usize, expected: &[u8]) -> Option<usize> {
+        b.get(idx).filter(|c| expected.contains(c)).map(|_| idx + 1)
+    }
+
+    let b = s.as_bytes();
+    let idx = digits(b, 0, 4)?;
+    let idx = digits(b, byte(b, idx, b"-")?, 2)?;
+    let idx = digits(b, byte(b, idx, b"-")?, 2)?;
+    let idx = digits(b, byte(b, idx, b"Tt ")?, 2)?;
+    let mut idx = digits(b, byte(b, idx, b":")?, 2)?;
+    if let Some(i) = byte(b, idx, b":").and_then(|i| digits(b, i, 2)) {
+        idx = i;
+        if let Some(i) = byte(b, idx, b".,").and_then(|i| digits(b, i, 1)) {
+            idx = i + b[i..].iter().take_while(|c| c.is_ascii_digit()).count();
+        }
+    }
+    if let Some(i) = byte(b, idx, b"Zz") {
+        idx = i;
+    } else if let Some(i) = byte(b, idx, b"+-").and_then(|i| digits(b, i, 2)) {
+        idx = i;
+        if let Some(i) = digits(b, idx, 2).or_else(|| digits(b, byte(b, idx, b":")?, 2)) {
+            idx = i;
+        }
+    } else {
+        // a trailing timezone abbreviation, numeric offset or AM/PM would extend the timestamp
+        let next = s[idx..].trim_start();
+        let token = next.split_whitespace().next().unwrap_or_default();
+        if next.len() < s.len() - idx
+            && (token.eq_ignore_ascii_case("AM")
+                || token.eq_ignore_ascii_case("PM")
+                || parse_numeric_offset(token).is_ok()
+                || parse_offset(token).is_ok())
+        {
+            return None;
+        }
+    }
+    match b.get(idx) {
+        Some(c) if c.is_ascii_alphanumeric() => None,
+        _ => Some(idx),
+    }
+}
+
+fn parse_unknown_alpha(s: &str) -> Result<(DateTime<FixedOffset>, Format), Error> {
     parse_unix_timestamp(s)
-        .or_else(|_| parse_rfc3339(s))
-        .or_else(|_| parse_rfc2822(s))
-        .or_else(|_| parse_is08601(s))
-        .or_else(|_| parse_naive_datetime(s))
-        .or_else(|_| parse_utc_naive_datetime_unknown_alpha(s))
-        .or_else(|_| parse_utc_naive_datetime_replace_str_unknown_alpha(s))
+        .map(|dt| (dt, Format::UnixTimestamp))
+        .or_else(|_| parse_git_raw(s).map(|dt| (dt, Format::Git)))
+        .or_else(|_| parse_rfc3339(s).map(|dt| (dt, Format::Rfc3339)))
+        .or_else(|_| parse_rfc2822(s).map(|dt| (dt, Format::Rfc2822)))
+        .or_else(|_| crate::email::parse_known_zones(s).map(|dt| (dt, Format::Rfc5322)))
+        .or_else(|_| parse_is08601(s).map(|dt| (dt, Format::Iso8601)))
+        .or_else(|_| {
+            // times without an offset are assumed to be in UTC
+            let (ndt, offset) = parse_iso_basic(s)?;
+            let dt = offset
+                .unwrap_or(Utc.fix())
+                .from_local_datetime(&ndt)
+                .single()
+                .ok_or(Error::InvalidDateTime)?;
+            Ok((dt, Format::Iso8601))
+        })
+        .or_else(|_: Error| parse_naive_datetime(s).map(strftime))
+        .or_else(|_| parse_go(s).map(strftime))
+        .or_else(|_| parse_common_log(s).map(strftime))
+        .or_else(|_| crate::asn1::parse(s).map(|dt| (dt, Format::Asn1)))
+        .or_else(|_| parse_utc_naive_datetime_unknown_alpha(s).map(strftime))
+        .or_else(|_| parse_javascript_locale(s).map(strftime))
+        .or_else(|_| parse_utc_naive_datetime_replace_str_unknown_alpha(s).map(strftime))
+        .or_else(|_| crate::sql::parse(s).map(|dt| (dt, Format::Sql)))
+        .or_else(|_| crate::japanese::parse(s).map(|dt| (dt, Format::Japanese)))
         .or_else(|_| {
-            let dt = crate::date::parse_unknown_alpha(s).map_err(|_| Error::InvalidDateTime)?;
+            let (dt, format) =
+                crate::date::parse_unknown_alpha(s).map_err(|_| Error::InvalidDateTime)?;
             let ndt = NaiveDateTime::new(
                 dt,
                 NaiveTime::from_num_seconds_from_midnight_opt(0, 0).unwrap_or_default(),
             );
-            Ok(Utc.fix().from_utc_datetime(&ndt))
+            Ok((Utc.fix().from_utc_datetime(&ndt), format))
         })
-        .or_else(|_: Error| parse_timezone_abbreviation_unknown_alpha(s))
+        .or_else(|_: Error| crate::yaml::parse(s).map(|dt| (dt, Format::Yaml)))
+        .or_else(|_| parse_mercurial_raw(s).map(|dt| (dt, Format::Mercurial)))
+        .or_else(|_| parse_timezone_abbreviation_unknown_alpha(s))
 }
 
-fn parse_with_alpha(s: &str) -> Result<DateTime<FixedOffset>, Error> {
-    parse_rfc2822(s)
-        .or_else(|_| parse_naive_datetime(s))
-        .or_else(|_| parse_utc_naive_datetime_alpha_prefix(s))
-        .or_else(|_| parse_utc_naive_datetime_replace_str_prefix_alpha(s))
+fn parse_with_alpha(s: &str) -> Result<(DateTime<FixedOffset>, Format), Error> {
+    parse_wcf_json(s)
+        .map(|dt| (dt, Format::WcfJson))
         .or_else(|_| {
-            let dt = crate::date::parse_with_alpha(s)
-                .map_err(|_| Error::InvalidDateTime)?
-                .and_time(NaiveTime::default())
-                .and_utc();
-            Ok(dt.fixed_offset())
+            let raw = s.strip_prefix('@').ok_or(Error::InvalidDateTime)?;
+            parse_git_raw_offset(raw)
+                .or_else(|_| parse_raw_timestamp(raw, Utc.fix()))
+                .map(|dt| (dt, Format::Git))
+        })
+        .or_else(|_| parse_rfc2822(s).map(|dt| (dt, Format::Rfc2822)))
+        .or_else(|_| {
+            crate::http::parse_fixed_window(s).map(|dt| (dt.fixed_offset(), Format::HttpDate))
+        })
+        .or_else(|_| crate::email::parse_known_zones(s).map(|dt| (dt, Format::Rfc5322)))
+        .or_else(|_| parse_javascript(s).map(strftime))
+        .or_else(|_| parse_zone_before_year(s))
+        .or_else(|_| parse_git_log(s).map(strftime))
+        .or_else(|_| parse_common_log(s).map(strftime))
+        .or_else(|_| parse_naive_datetime(s).map(strftime))
+        .or_else(|_| parse_utc_naive_datetime_alpha_prefix(s).map(strftime))
+        .or_else(|_| parse_utc_naive_datetime_replace_str_prefix_alpha(s).map(strftime))
+        .or_else(|_| crate::sql::parse(s).map(|dt| (dt, Format::Sql)))
+        .or_else(|_| crate::japanese::parse(s).map(|dt| (dt, Format::Japanese)))
+        .or_else(|_| {
+            let (dt, format) =
+                crate::date::parse_with_alpha(s).map_err(|_| Error::InvalidDateTime)?;
+            let dt = dt.and_time(NaiveTime::default()).and_utc();
+            Ok((dt.fixed_offset(), format))
         })
         .or_else(|_: Error| parse_timezone_abbreviation_prefix_alpha(s))
 }
 
+fn strftime((dt, fmt): (DateTime<FixedOffset>, &'static str)) -> (DateTime<FixedOffset>, Format) {
+    (dt, Format::Strftime(fmt))
+}
+
 fn parse_unix_timestamp(s: &str) -> Result<DateTime<FixedOffset>, Error> {
     if s.len() <= 10 {
         // unix timestamp - seconds
@@ -113,6 +261,30 @@ fn parse_is08601(s: &str) -> Result<DateTime<FixedOffset>, Error> {
         .map_err(|_| Error::InvalidDateTime)
 }
 
+// ISO 8601 basic format eg. `20211108T003245Z`, `20211108T013245.426+0100` or `20211108T0032`,
+// returning the offset separately as times without one are local times.
+pub(crate) fn parse_iso_basic(s: &str) -> Result<(NaiveDateTime, Option<FixedOffset>), Error> {
+    let (naive, offset) = if let Some(naive) = s.strip_suffix(['Z', 'z']) {
+        (naive, Some(Utc.fix()))
+    } else if let Some(idx) = s.rfind(['+', '-']) {
+        let tz = &s[idx..];
+        if !tz[1..].bytes().all(|b| b.is_ascii_digit() || b == b':') {
+            return Err(Error::InvalidDateTime);
+        }
+        (&s[..idx], Some(parse_numeric_offset(tz)?))
+    } else {
+        (s, None)
+    };
+    if naive.len() < 13 || naive.as_bytes()[8] != b'T' {
+        return Err(Error::InvalidDateTime);
+    }
+    ["%Y%m%dT%H%M%S%.f", "%Y%m%dT%H%M"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(naive, fmt).ok())
+        .map(|ndt| (ndt, offset))
+        .ok_or(Error::InvalidDateTime)
+}
+
 fn parse_rfc3339(s: &str) -> Result<DateTime<FixedOffset>, Error> {
     DateTime::parse_from_rfc3339(s).map_err(|_| Error::InvalidDateTime)
 }
@@ -121,25 +293,225 @@ fn parse_rfc2822(s: &str) -> Result<DateTime<FixedOffset>, Error> {
     DateTime::parse_from_rfc2822(s).map_err(|_| Error::InvalidDateTime)
 }
 
-fn parse_naive_datetime(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+// WCF `DataContractJsonSerializer` eg. `/Date(1636331565426+0100)/`, optionally with the slashes
+// escaped as in the raw JSON. The milliseconds are always relative to the UTC epoch, the offset
+// only records the zone the value was serialized in.
+fn parse_wcf_json(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let inner = s
+        .strip_prefix("/Date(")
+        .and_then(|s| s.strip_suffix(")/"))
+        .or_else(|| {
+            s.strip_prefix("\\/Date(")
+                .and_then(|s| s.strip_suffix(")\\/"))
+        })
+        .ok_or(Error::InvalidDateTime)?;
+    // skip the sign of negative timestamps
+    let split = inner
+        .get(1..)
+        .and_then(|rest| rest.find(['+', '-']))
+        .map_or(inner.len(), |idx| idx + 1);
+    let (millis, offset) = inner.split_at(split);
+    let digits = millis.strip_prefix('-').unwrap_or(millis);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    let offset = match offset.len() {
+        0 => Utc.fix(),
+        5 => parse_numeric_offset(offset)?,
+        _ => return Err(Error::InvalidDateTime),
+    };
+    let millis = millis.parse().map_err(|_| Error::InvalidDateTime)?;
+    let dt = DateTime::from_timestamp_millis(millis).ok_or(Error::InvalidDateTime)?;
+    Ok(dt.with_timezone(&offset))
+}
+
+fn parse_naive_datetime(s: &str) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
     // DateTimes with timezone info
     const PARSE_FORMATS: &[&str] = &[
         "%Y-%m-%d %H:%M:%S %z",
         "%Y-%m-%d %H:%M:%S%.f%#z",
         "%Y-%m-%d %H:%M:%S%#z",
         "%Y-%m-%d %H:%M%#z",
+        "%Y:%m:%d %H:%M:%S%.f%#z",
+    ];
+    PARSE_FORMATS
+        .iter()
+        .map(|fmt| DateTime::parse_from_str(s, fmt).map(|dt| (dt, *fmt)))
+        .find_map(Result::ok)
+        .map_or_else(|| Err(Error::InvalidDateTime), Ok)
+}
+
+// Go's `time.Time.String()` eg. `2021-11-08 00:32:45.426737 +0000 UTC m=+0.000123456`, the
+// monotonic clock reading is only meaningful within the emitting process so is ignored.
+fn parse_go(s: &str) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
+    let s = match s.rsplit_once(" m=") {
+        Some((s, m))
+            if m.strip_prefix(['+', '-']).is_some_and(|m| {
+                !m.is_empty() && m.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+            }) =>
+        {
+            s
+        }
+        _ => s,
+    };
+    let (s, abbreviation) = s.rsplit_once(' ').ok_or(Error::InvalidDateTime)?;
+    let dt = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f %z")
+        .map_err(|_| Error::InvalidDateTime)?;
+    if !go_abbreviation_matches(abbreviation, *dt.offset()) {
+        return Err(Error::InvalidDateTime);
+    }
+    Ok((dt, "%Y-%m-%d %H:%M:%S%.f %z %Z"))
+}
+
+// Go uses the numeric offset eg. `+07` for zones without an abbreviation, otherwise these are
+// three to five uppercase letters as in the tz database. Unknown abbreviations, and ambiguous
+// ones such as IST which Go uses for India, are accepted as is.
+fn go_abbreviation_matches(abbreviation: &str, offset: FixedOffset) -> bool {
+    const AMBIGUOUS: &[&str] = &[
+        "ACT", "AMT", "AST", "BDT", "BST", "CDT", "CST", "CT", "ECT", "GST", "IST", "MST", "PST",
+        "SST", "WST",
     ];
+    if let Ok(numeric) = parse_numeric_offset(abbreviation) {
+        return numeric == offset;
+    }
+    if !(3..=5).contains(&abbreviation.len())
+        || !abbreviation.bytes().all(|b| b.is_ascii_uppercase())
+    {
+        return false;
+    }
+    AMBIGUOUS.contains(&abbreviation) || parse_offset(abbreviation).map_or(true, |o| o == offset)
+}
+
+// Apache/NCSA Common & Combined Log Format and nginx `$time_local`, optionally wrapped in brackets
+// as they appear in access logs eg. `[10/Oct/2000:13:55:36 -0700]`
+fn parse_common_log(s: &str) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
+    const PARSE_FORMATS: &[&str] = &["%d/%b/%Y:%H:%M:%S %z", "%d/%b/%Y:%H:%M:%S%.f %z"];
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s);
     PARSE_FORMATS
         .iter()
-        .map(|fmt| DateTime::parse_from_str(s, fmt))
+        .map(|fmt| DateTime::parse_from_str(s, fmt).map(|dt| (dt, *fmt)))
         .find_map(Result::ok)
         .map_or_else(|| Err(Error::InvalidDateTime), Ok)
 }
 
-fn parse_utc_naive_datetime_unknown_alpha(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+// JavaScript `Date.prototype.toString()` eg.
+// `Mon Nov 08 2021 00:32:45 GMT+0000 (Coordinated Universal Time)`, the parenthesized zone name
+// is implementation dependent and optional so is ignored in favour of the offset.
+fn parse_javascript(s: &str) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
+    const FORMAT: &str = "%a %b %d %Y %H:%M:%S GMT%z";
+    let s = match s.find(" (") {
+        Some(idx) if s.ends_with(')') => &s[..idx],
+        _ => s,
+    };
+    DateTime::parse_from_str(s, FORMAT)
+        .map(|dt| (dt, FORMAT))
+        .map_err(|_| Error::InvalidDateTime)
+}
+
+// `git log` and `hg log` eg. `Mon Nov 8 00:32:45 2021 +0000`.
+fn parse_git_log(s: &str) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
+    const FORMAT: &str = "%a %b %e %H:%M:%S %Y %z";
+    DateTime::parse_from_str(s, FORMAT)
+        .map(|dt| (dt, FORMAT))
+        .map_err(|_| Error::InvalidDateTime)
+}
+
+// git's raw commit object format eg. `1636331565 +0100`, the instant is the unix timestamp and
+// the offset the zone it was committed in.
+//
+// Without the `@` prefix, which is handled by `parse_git_raw_offset` directly, the timestamp must
+// have at least 9 digits so short numbers followed by an offset aren't misread.
+fn parse_git_raw(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    match s.split_once(' ') {
+        Some((timestamp, _)) if timestamp.trim_start_matches('-').len() >= 9 => {
+            parse_git_raw_offset(s)
+        }
+        _ => Err(Error::InvalidDateTime),
+    }
+}
+
+fn parse_git_raw_offset(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (timestamp, tz) = s.split_once(' ').ok_or(Error::InvalidDateTime)?;
+    if tz.len() != 5 {
+        return Err(Error::InvalidDateTime);
+    }
+    parse_raw_timestamp(timestamp, parse_numeric_offset(tz)?)
+}
+
+// Mercurial's raw format eg. `1636331565 -3600`, with the offset in seconds west of UTC.
+//
+// As any pair of numbers would otherwise match, the timestamp must have at least 9 digits and the
+// offset must be a whole number of minutes no more than 14 hours from UTC.
+fn parse_mercurial_raw(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (timestamp, tz) = s.split_once(' ').ok_or(Error::InvalidDateTime)?;
+    let digits = tz.strip_prefix('-').unwrap_or(tz);
+    if digits.is_empty()
+        || !digits.bytes().all(|b| b.is_ascii_digit())
+        || timestamp.trim_start_matches('-').len() < 9
+    {
+        return Err(Error::InvalidDateTime);
+    }
+    let offset = tz
+        .parse::<i32>()
+        .ok()
+        .filter(|secs| secs % 60 == 0 && secs.abs() <= 14 * 3600)
+        .and_then(FixedOffset::west_opt)
+        .ok_or(Error::InvalidDateTime)?;
+    parse_raw_timestamp(timestamp, offset)
+}
+
+// a unix timestamp in seconds, shown in the provided offset.
+fn parse_raw_timestamp(s: &str, offset: FixedOffset) -> Result<DateTime<FixedOffset>, Error> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    s.parse()
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .map(|dt| dt.with_timezone(&offset))
+        .ok_or(Error::InvalidDateTime)
+}
+
+// Java `Date.toString()`, GNU `date` and Twitter's `created_at` place the zone between the time
+// and the year eg. `Mon Nov 08 00:32:45 UTC 2021` or `Mon Nov 08 00:32:45 +0000 2021`.
+fn parse_zone_before_year(s: &str) -> Result<(DateTime<FixedOffset>, Format), Error> {
+    const NUMERIC_FORMAT: &str = "%a %b %e %H:%M:%S %z %Y";
+    const ABBREVIATION_FORMAT: &str = "%a %b %e %H:%M:%S %Z %Y";
+    if let Ok(dt) = DateTime::parse_from_str(s, NUMERIC_FORMAT) {
+        return Ok((dt, Format::Strftime(NUMERIC_FORMAT)));
+    }
+    let (rest, year) = s.rsplit_once(' ').ok_or(Error::InvalidDateTime)?;
+    let (datetime, tz) = rest.rsplit_once(' ').ok_or(Error::InvalidDateTime)?;
+    let offset = parse_offset(tz)?;
+    let ndt = NaiveDateTime::parse_from_str(&format!("{datetime} {year}"), "%a %b %e %H:%M:%S %Y")
+        .map_err(|_| Error::InvalidDateTime)?;
+    let dt = offset
+        .from_local_datetime(&ndt)
+        .single()
+        .ok_or(Error::InvalidDateTime)?;
+    Ok((dt, Format::Strftime(ABBREVIATION_FORMAT)))
+}
+
+// JavaScript `Date.prototype.toLocaleString('en-US')` eg. `11/8/2021, 12:32:45 AM`, recent
+// engines separate the day period with a narrow no-break space.
+fn parse_javascript_locale(s: &str) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
+    const PARSE_FORMATS: &[&str] = &["%m/%d/%Y, %I:%M:%S %P"];
+    let s = s.replace('\u{202f}', " ");
+    parse_utc_naive_datetime(&s, PARSE_FORMATS)
+}
+
+fn parse_utc_naive_datetime_unknown_alpha(
+    s: &str,
+) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
     // DateTimes without timezone info
     const PARSE_FORMATS: &[&str] = &[
         "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y:%m:%d %H:%M:%S%.f",
         "%Y-%m-%d %H:%M:%S",
         "%Y-%m-%d %H:%M",
         "%m/%d/%y %H:%M:%S",
@@ -165,7 +537,9 @@ fn parse_utc_naive_datetime_unknown_alpha(s: &str) -> Result<DateTime<FixedOffse
     parse_utc_naive_datetime(s, PARSE_FORMATS)
 }
 
-fn parse_utc_naive_datetime_alpha_prefix(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+fn parse_utc_naive_datetime_alpha_prefix(
+    s: &str,
+) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
     // DateTimes without timezone info
     const PARSE_FORMATS: &[&str] = &["%A %B %e %T %Y"];
     parse_utc_naive_datetime(s, PARSE_FORMATS)
@@ -173,7 +547,7 @@ fn parse_utc_naive_datetime_alpha_prefix(s: &str) -> Result<DateTime<FixedOffset
 
 fn parse_utc_naive_datetime_replace_str_unknown_alpha(
     s: &str,
-) -> Result<DateTime<FixedOffset>, Error> {
+) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
     // DateTimes without timezone info
     const PARSE_FORMATS: &[&str] = &[
         "%d %B %Y %H:%M:%S",
@@ -188,7 +562,7 @@ fn parse_utc_naive_datetime_replace_str_unknown_alpha(
 
 fn parse_utc_naive_datetime_replace_str_prefix_alpha(
     s: &str,
-) -> Result<DateTime<FixedOffset>, Error> {
+) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
     // DateTimes without timezone info
     const PARSE_FORMATS: &[&str] = &[
         "%B %d %Y %H:%M:%S",
@@ -200,14 +574,17 @@ fn parse_utc_naive_datetime_replace_str_prefix_alpha(
     parse_utc_naive_datetime(&s, PARSE_FORMATS)
 }
 
-fn parse_utc_naive_datetime(s: &str, formats: &[&str]) -> Result<DateTime<FixedOffset>, Error> {
+fn parse_utc_naive_datetime(
+    s: &str,
+    formats: &[&'static str],
+) -> Result<(DateTime<FixedOffset>, &'static str), Error> {
     formats
         .iter()
-        .map(|fmt| NaiveDateTime::parse_from_str(s, fmt))
+        .map(|fmt| NaiveDateTime::parse_from_str(s, fmt).map(|dt| (dt, *fmt)))
         .find_map(Result::ok)
         .map_or_else(
             || Err(Error::InvalidDateTime),
-            |dt| Ok(DateTime::from(dt.and_utc())),
+            |(dt, fmt)| Ok((DateTime::from(dt.and_utc()), fmt)),
         )
 }
 
@@ -218,15 +595,20 @@ fn parse_utc_naive_datetime(s: &str, formats: &[&str]) -> Result<DateTime<FixedO
 //
 // list sourced from https://www.utctime.net/time-zone-abbreviations
 //
-fn parse_timezone_abbreviation_unknown_alpha(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+fn parse_timezone_abbreviation_unknown_alpha(
+    s: &str,
+) -> Result<(DateTime<FixedOffset>, Format), Error> {
     s.rsplit_once(' ').map_or_else(
         || Err(Error::InvalidDateTime),
         |(s, tz)| {
             let offset = parse_offset(tz)?;
-            let dt = parse_utc_naive_datetime_unknown_alpha(s)
-                .or_else(|_| parse_utc_naive_datetime_replace_str_unknown_alpha(s))?
-                - offset;
-            Ok(offset.from_utc_datetime(&dt.naive_utc()))
+            let (dt, fmt) = parse_utc_naive_datetime_unknown_alpha(s)
+                .or_else(|_| parse_utc_naive_datetime_replace_str_unknown_alpha(s))?;
+            let dt = dt - offset;
+            Ok((
+                offset.from_utc_datetime(&dt.naive_utc()),
+                Format::TimezoneAbbreviation(fmt),
+            ))
         },
     )
 }
@@ -238,79 +620,81 @@ fn parse_timezone_abbreviation_unknown_alpha(s: &str) -> Result<DateTime<FixedOf
 //
 // list sourced from https://www.utctime.net/time-zone-abbreviations
 //
-fn parse_timezone_abbreviation_prefix_alpha(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+fn parse_timezone_abbreviation_prefix_alpha(
+    s: &str,
+) -> Result<(DateTime<FixedOffset>, Format), Error> {
     s.rsplit_once(' ').map_or_else(
         || Err(Error::InvalidDateTime),
         |(s, tz)| {
             let offset = parse_offset(tz)?;
-            let dt = parse_utc_naive_datetime_alpha_prefix(s)
-                .or_else(|_| parse_utc_naive_datetime_replace_str_prefix_alpha(s))?
-                - offset;
-            Ok(offset.from_utc_datetime(&dt.naive_utc()))
+            let (dt, fmt) = parse_utc_naive_datetime_alpha_prefix(s)
+                .or_else(|_| parse_utc_naive_datetime_replace_str_prefix_alpha(s))?;
+            let dt = dt - offset;
+            Ok((
+                offset.from_utc_datetime(&dt.naive_utc()),
+                Format::TimezoneAbbreviation(fmt),
+            ))
         },
     )
 }
 
 #[allow(clippy::too_many_lines)]
-fn parse_offset(tz: &str) -> Result<FixedOffset, Error> {
-    let offset = match tz.to_uppercase().as_str() {
-        "GMT" | "IBST" | "WET" | "Z" | "EGST" => Utc.fix(),
+pub(crate) fn parse_offset(tz: &str) -> Result<FixedOffset, Error> {
+    // numeric offsets relative to GMT or UTC eg. `GMT+0100` as output by JavaScript engines
+    if let Some(prefix) = tz.get(..3) {
+        if prefix.eq_ignore_ascii_case("GMT") || prefix.eq_ignore_ascii_case("UTC") {
+            if let Ok(offset) = parse_numeric_offset(&tz[3..]) {
+                return Ok(offset);
+            }
+        }
+    }
+    // uppercase on the stack, this is called speculatively on arbitrary tokens
+    let mut buf = [0u8; 32];
+    let buf = buf.get_mut(..tz.len()).ok_or(Error::InvalidDateTime)?;
+    buf.copy_from_slice(tz.as_bytes());
+    buf.make_ascii_uppercase();
+    let offset = match std::str::from_utf8(buf).map_err(|_| Error::InvalidDateTime)? {
+        "GMT" | "UT" | "UTC" | "IBST" | "WET" | "Z" | "EGST" => Utc.fix(),
         "BST" | "CET" | "DFT" | "IST" | "MET" | "WAT" | "WEDT" | "WEST" => {
             FixedOffset::east_opt(3600).unwrap_or(Utc.fix())
         }
-        "CAT" | "CEDT" | "CEST" | "EET" | "HAEC" | "IST Israel" | "MEST" | "SAST" | "USZ1"
-        | "WAST" | "AST Arabia" | "EAT" => FixedOffset::east_opt(2 * 3600).unwrap_or(Utc.fix()),
+        "CAT" | "CEDT" | "CEST" | "EET" | "HAEC" | "MEST" | "SAST" | "USZ1" | "WAST" | "EAT" => {
+            FixedOffset::east_opt(2 * 3600).unwrap_or(Utc.fix())
+        }
         "EEDT" | "EEST" | "FET" | "IDT" | "IOT" | "MSK" | "SYOT" => {
             FixedOffset::east_opt(3 * 3600).unwrap_or(Utc.fix())
         }
         "IRST" => FixedOffset::east_opt(3 * 3600 + 1800).unwrap_or(Utc.fix()),
-        "AMT Armenia" | "AZT" | "GET" | "GST Gulf" | "MUT" | "RET" | "SAMT" | "SCT" | "VOLT" => {
+        "AZT" | "GET" | "MUT" | "RET" | "SAMT" | "SCT" | "VOLT" => {
             FixedOffset::east_opt(4 * 3600).unwrap_or(Utc.fix())
         }
         "AFT" | "IRDT" => FixedOffset::east_opt(4 * 3600 + 1800).unwrap_or(Utc.fix()),
         "HMT" | "MAWT" | "MVT" | "ORAT" | "PKT" | "TFT" | "TJT" | "TMT" | "UZT" | "YEKT" => {
             FixedOffset::east_opt(5 * 3600).unwrap_or(Utc.fix())
         }
-        "IST Indian" | "SLST" => FixedOffset::east_opt(5 * 3600 + 1800).unwrap_or(Utc.fix()),
+        "SLST" => FixedOffset::east_opt(5 * 3600 + 1800).unwrap_or(Utc.fix()),
         "NPT" => FixedOffset::east_opt(5 * 3600 + 2700).unwrap_or(Utc.fix()),
-        "BDT Bangladesh" | "BIOT" | "BST Bangladesh" | "BTT" | "KGT" | "OMST" | "VOST" => {
+        "BIOT" | "BTT" | "KGT" | "OMST" | "VOST" => {
             FixedOffset::east_opt(6 * 3600).unwrap_or(Utc.fix())
         }
-        "CCT" | "MMT" | "MST Myanmar" => {
-            FixedOffset::east_opt(6 * 3600 + 1800).unwrap_or(Utc.fix())
-        }
+        "CCT" | "MMT" => FixedOffset::east_opt(6 * 3600 + 1800).unwrap_or(Utc.fix()),
         "CXT" | "DAVT" | "HOVT" | "ICT" | "KRAT" | "THA" | "WIT" => {
             FixedOffset::east_opt(7 * 3600).unwrap_or(Utc.fix())
         }
-        "ACT" | "AWST" | "BDT" | "CHOT" | "CIT" | "CST China" | "CT" | "HKT" | "IRKT"
-        | "MST Malaysia" | "MYT" | "PST Philippine" | "SGT" | "SST" | "ULAT" | "WST" => {
-            FixedOffset::east_opt(8 * 3600).unwrap_or(Utc.fix())
-        }
+        "ACT" | "AWST" | "BDT" | "CHOT" | "CIT" | "CT" | "HKT" | "IRKT" | "MYT" | "SGT" | "SST"
+        | "ULAT" | "WST" => FixedOffset::east_opt(8 * 3600).unwrap_or(Utc.fix()),
         "CWST" => FixedOffset::east_opt(8 * 3600 + 2700).unwrap_or(Utc.fix()),
         "AWDT" | "EIT" | "JST" | "KST" | "TLT" | "YAKT" => {
             FixedOffset::east_opt(9 * 3600).unwrap_or(Utc.fix())
         }
-        "ACST" | "CST Australia Central" => {
-            FixedOffset::east_opt(9 * 3600 + 1800).unwrap_or(Utc.fix())
-        }
-        "AEST" | "ChST" | "CHUT" | "DDUT" | "EST Australia" | "PGT" | "VLAT" => {
+        "ACST" => FixedOffset::east_opt(9 * 3600 + 1800).unwrap_or(Utc.fix()),
+        "AEST" | "CHUT" | "DDUT" | "PGT" | "VLAT" => {
             FixedOffset::east_opt(10 * 3600).unwrap_or(Utc.fix())
         }
-        "ACDT" | "CST Australia Central Summer" | "LHST" => {
-            FixedOffset::east_opt(10 * 3600 + 1800).unwrap_or(Utc.fix())
-        }
-        "AEDT"
-        | "BST Bougainville"
-        | "KOST"
-        | "LHST Lord Howe Summer"
-        | "MIST"
-        | "NCT"
-        | "PONT"
-        | "SAKT"
-        | "SBT"
-        | "SRET"
-        | "VUT"
-        | "NFT" => FixedOffset::east_opt(11 * 3600).unwrap_or(Utc.fix()),
+        "ACDT" | "LHST" => FixedOffset::east_opt(10 * 3600 + 1800).unwrap_or(Utc.fix()),
+        "AEDT" | "KOST" | "MIST" | "NCT" | "PONT" | "SAKT" | "SBT" | "SRET" | "VUT" | "NFT" => {
+            FixedOffset::east_opt(11 * 3600).unwrap_or(Utc.fix())
+        }
         "FJT" | "GILT" | "MAGT" | "MHT" | "NZST" | "PETT" | "TVT" | "WAKT" => {
             FixedOffset::east_opt(12 * 3600).unwrap_or(Utc.fix())
         }
@@ -323,24 +707,14 @@ fn parse_offset(tz: &str) -> Result<FixedOffset, Error> {
             FixedOffset::west_opt(2 * 3600).unwrap_or(Utc.fix())
         }
         "NDT" => FixedOffset::west_opt(2 * 3600 + 1800).unwrap_or(Utc.fix()),
-        "ADT"
-        | "AMST"
-        | "ART"
-        | "BRT"
-        | "CLST"
-        | "FKST"
-        | "FKST Falkland Islands Summer"
-        | "GFT"
-        | "PMST"
-        | "PYST"
-        | "ROTT"
-        | "SRT"
-        | "UYT" => FixedOffset::west_opt(3 * 3600).unwrap_or(Utc.fix()),
+        "ADT" | "AMST" | "ART" | "BRT" | "CLST" | "FKST" | "GFT" | "PMST" | "PYST" | "ROTT"
+        | "SRT" | "UYT" => FixedOffset::west_opt(3 * 3600).unwrap_or(Utc.fix()),
         "NST" | "NT" => FixedOffset::west_opt(3 * 3600 + 1800).unwrap_or(Utc.fix()),
-        "AMT" | "AST" | "BOT" | "CDT Cuba" | "CLT" | "COST" | "ECT" | "EDT" | "FKT" | "GYT"
-        | "PYT" => FixedOffset::west_opt(4 * 3600).unwrap_or(Utc.fix()),
+        "AMT" | "AST" | "BOT" | "CLT" | "COST" | "ECT" | "EDT" | "FKT" | "GYT" | "PYT" => {
+            FixedOffset::west_opt(4 * 3600).unwrap_or(Utc.fix())
+        }
         "VET" => FixedOffset::west_opt(4 * 3600 + 1800).unwrap_or(Utc.fix()),
-        "ACT Acre" | "CDT" | "COT" | "CST Cuba" | "EASST" | "ECT Ecuador" | "EST" | "PET" => {
+        "CDT" | "COT" | "EASST" | "EST" | "PET" => {
             FixedOffset::west_opt(5 * 3600).unwrap_or(Utc.fix())
         }
         "CST" | "EAST" | "GALT" | "MDT" => FixedOffset::west_opt(6 * 3600).unwrap_or(Utc.fix()),
@@ -349,13 +723,66 @@ fn parse_offset(tz: &str) -> Result<FixedOffset, Error> {
         "AKST" | "GAMT" | "GIT" | "HADT" => FixedOffset::west_opt(9 * 3600).unwrap_or(Utc.fix()),
         "MART" | "MIT" => FixedOffset::west_opt(9 * 3600 + 1800).unwrap_or(Utc.fix()),
         "CKT" | "HAST" | "HST" | "TAHT" => FixedOffset::west_opt(10 * 3600).unwrap_or(Utc.fix()),
-        "NUT" | "SST Samoa" => FixedOffset::west_opt(11 * 3600).unwrap_or(Utc.fix()),
+        "NUT" => FixedOffset::west_opt(11 * 3600).unwrap_or(Utc.fix()),
         "BIT" => FixedOffset::west_opt(12 * 3600).unwrap_or(Utc.fix()),
         _ => return Err(Error::InvalidDateTime),
     };
     Ok(offset)
 }
 
+// replaces a comma decimal separator before the fraction of a second eg. `00:32:45,426` or
+// `20211108T003245,426Z` with a `.`, as used by log4j and Python's logging and allowed by ISO 8601.
+pub(crate) fn normalize_fraction(s: &str) -> Cow<'_, str> {
+    let b = s.as_bytes();
+    let idx = (3..b.len().saturating_sub(1)).find(|&i| {
+        b[i] == b','
+            && b[i - 2..i].iter().all(u8::is_ascii_digit)
+            && b[i + 1].is_ascii_digit()
+            && (b[i - 3] == b':'
+                || (i >= 7 && b[i - 7] == b'T' && b[i - 6..i].iter().all(u8::is_ascii_digit)))
+    });
+    match idx {
+        Some(idx) => {
+            let mut s = s.to_owned();
+            s.replace_range(idx..=idx, ".");
+            Cow::Owned(s)
+        }
+        None => Cow::Borrowed(s),
+    }
+}
+
+// parses a numeric offset in the form of `+hh`, `+hhmm` or `+hh:mm`.
+pub(crate) fn parse_numeric_offset(tz: &str) -> Result<FixedOffset, Error> {
+    let (sign, digits) = match tz.as_bytes().first() {
+        Some(b'+') => (1, &tz[1..]),
+        Some(b'-') => (-1, &tz[1..]),
+        _ => return Err(Error::InvalidDateTime),
+    };
+    // also ensures the splits below are on char boundaries
+    if !digits.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+        return Err(Error::InvalidDateTime);
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits, "0"),
+        4 => digits.split_at(2),
+        5 if digits.as_bytes()[2] == b':' => (&digits[..2], &digits[3..]),
+        _ => return Err(Error::InvalidDateTime),
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return Err(Error::InvalidDateTime);
+    }
+    let hours: i32 = hours.parse().map_err(|_| Error::InvalidDateTime)?;
+    let minutes: i32 = minutes.parse().map_err(|_| Error::InvalidDateTime)?;
+    if minutes > 59 {
+        return Err(Error::InvalidDateTime);
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or(Error::InvalidDateTime)
+}
+
 #[cfg(test)]
 #[allow(clippy::unreadable_literal)]
 mod tests {
@@ -418,6 +845,446 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn javascript() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            (
+                "Mon Nov 08 2021 00:32:45 GMT+0000 (Coordinated Universal Time)",
+                1636331565000000000,
+            ),
+            (
+                "Sun Nov 07 2021 16:32:45 GMT-0800 (Pacific Standard Time)",
+                1636331565000000000,
+            ),
+            (
+                "Mon Nov 08 2021 01:32:45 GMT+0100 (CET)",
+                1636331565000000000,
+            ),
+            ("Mon Nov 08 2021 00:32:45 GMT+0000", 1636331565000000000),
+            ("11/8/2021, 12:32:45 AM", 1636331565000000000),
+            ("11/8/2021, 12:32:45\u{202f}AM", 1636331565000000000),
+            ("2021-11-08 01:32:45 GMT+0100", 1636331565000000000),
+            ("2021-11-08 05:32:45 UTC+05:00", 1636331565000000000),
+            // toUTCString
+            ("Mon, 08 Nov 2021 00:32:45 GMT", 1636331565000000000),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_utc(input)?.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn zone_before_year() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            // java
+            ("Mon Nov 08 00:32:45 UTC 2021", 1636331565),
+            ("Mon Nov 08 01:32:45 CET 2021", 1636331565),
+            ("Sun Nov 07 16:32:45 PST 2021", 1636331565),
+            // gnu date
+            ("Mon Nov  8 00:32:45 UTC 2021", 1636331565),
+            ("Mon Nov  8 00:32:45 GMT+0000 2021", 1636331565),
+            // twitter
+            ("Mon Nov 08 00:32:45 +0000 2021", 1636331565),
+            ("Mon Nov 08 05:32:45 +0500 2021", 1636331565),
+        ] {
+            assert_eq!(*expected, parse_utc(input)?.timestamp(), "{input}");
+        }
+        assert!(parse("Mon Nov 08 00:32:45 XYZ 2021").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn go() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            (
+                "2021-11-08 00:32:45.426737 +0000 UTC m=+0.000123456",
+                1636331565426737000,
+            ),
+            ("2021-11-08 00:32:45 -0700 MST", 1636356765000000000),
+            ("2021-11-08 00:32:45 +0000 UTC", 1636331565000000000),
+            ("2021-11-08 07:32:45 +0700 +07", 1636331565000000000),
+            ("2021-11-08 06:02:45 +0530 IST", 1636331565000000000),
+            ("2021-11-08 07:32:45 +0700 WIB", 1636331565000000000),
+            (
+                "2021-11-08 01:32:45.123456789 +0100 CET m=-12.5",
+                1636331565123456789,
+            ),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_utc(input)?.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+        }
+        for input in &[
+            "2021-11-08 00:32:45 +0000 PDT",
+            "2021-11-08 00:32:45 +0000 +07",
+            "2021-11-08 00:32:45 +0000 UTC m=junk",
+            "2021-11-08 00:32:45 +0000 utc",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dotnet() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected, offset) in &[
+            // wcf json
+            ("/Date(1636331565426)/", 1636331565426000000, 0),
+            ("/Date(1636331565426+0100)/", 1636331565426000000, 3600),
+            ("/Date(1636331565426-0700)/", 1636331565426000000, -25200),
+            ("\\/Date(1636331565426)\\/", 1636331565426000000, 0),
+            ("/Date(-1000)/", -1000000000, 0),
+            ("/Date(-1000+0530)/", -1000000000, 19800),
+            // round-trip "o" for each DateTimeKind
+            ("2021-11-08T00:32:45.4267370Z", 1636331565426737000, 0),
+            (
+                "2021-11-08T00:32:45.4267370-07:00",
+                1636356765426737000,
+                -25200,
+            ),
+            ("2021-11-08T00:32:45.4267370", 1636331565426737000, 0),
+        ] {
+            let dt = parse(input)?;
+            assert_eq!(*expected, dt.timestamp_nanos_opt().unwrap(), "{input}");
+            assert_eq!(*offset, dt.offset().local_minus_utc(), "{input}");
+        }
+        for input in &[
+            "/Date()/",
+            "/Date(+1000)/",
+            "/Date(1636331565426+01)/",
+            "/Date(1636331565426)",
+            "/Date(abc)/",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn exif_and_asn1() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            // exif
+            ("2021:11:08 00:32:45", 1636331565000000000),
+            ("2021:11:08 01:32:45.426+01:00", 1636331565426000000),
+            // asn.1
+            ("211108003245Z", 1636331565000000000),
+            ("20211108003245.426Z", 1636331565426000000),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_utc(input)?.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn iso_basic() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("20211108T003245Z", 1636331565000000000),
+            ("20211108T003245", 1636331565000000000),
+            ("20211108T013245.426+0100", 1636331565426000000),
+            ("20211107T163245-08", 1636331565000000000),
+            ("20211108T0032Z", 1636331520000000000),
+        ] {
+            let (dt, format) = parse_with_format(input)?;
+            assert_eq!(*expected, dt.timestamp_nanos_opt().unwrap(), "{input}");
+            assert_eq!(Format::Iso8601, format, "{input}");
+        }
+        assert!(parse("20211108T25Z").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "20211108T25Z",
+            "20211108T003245+01é0",
+            // non-ASCII following an offset sign must not panic
+            "11W時=+.é]",
+            "00午後)éZ日00ép+時(",
+            " 00)'+9日",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn comma_fractions() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            // log4j, logback and Python logging
+            ("2021-11-08 00:32:45,426", 1636331565426000000),
+            ("2021-11-08T00:32:45,426Z", 1636331565426000000),
+            ("2021-11-08 01:32:45,426 +0100", 1636331565426000000),
+            ("20211108T003245,426Z", 1636331565426000000),
+            ("11/08/2021 00:32:45,426", 1636331565426000000),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_utc(input)?.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+        }
+        let (dt, remainder) = parse_prefix("2021-11-08 00:32:45,426 INFO starting")?;
+        assert_eq!(1636331565426000000, dt.timestamp_nanos_opt().unwrap());
+        assert_eq!(" INFO starting", remainder);
+        assert!(parse("2021-11-08 00:32:45,").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn common_log() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected, offset, format) in &[
+            (
+                "[10/Oct/2000:13:55:36 -0700]",
+                971211336,
+                -25200,
+                "%d/%b/%Y:%H:%M:%S %z",
+            ),
+            (
+                "10/Oct/2000:13:55:36 -0700",
+                971211336,
+                -25200,
+                "%d/%b/%Y:%H:%M:%S %z",
+            ),
+            (
+                "[08/Nov/2021:01:32:45.426 +0100]",
+                1636331565,
+                3600,
+                "%d/%b/%Y:%H:%M:%S%.f %z",
+            ),
+        ] {
+            let (dt, parsed_format) = parse_with_format(input)?;
+            assert_eq!(*expected, dt.timestamp(), "{input}");
+            assert_eq!(*offset, dt.offset().local_minus_utc(), "{input}");
+            assert_eq!(Format::Strftime(format), parsed_format, "{input}");
+        }
+        let (dt, rest) = parse_prefix("[10/Oct/2000:13:55:36 -0700] \"GET / HTTP/1.0\" 200")?;
+        assert_eq!(971211336, dt.timestamp());
+        assert_eq!(" \"GET / HTTP/1.0\" 200", rest);
+
+        for input in &[
+            // missing offset
+            "10/Oct/2000:13:55:36",
+            "[10/Oct/2000:13:55:36]",
+            // unmatched brackets
+            "[10/Oct/2000:13:55:36 -0700",
+            "10/Oct/2000:13:55:36 -0700]",
+            // bad month abbreviation and day
+            "10/Okt/2000:13:55:36 -0700",
+            "32/Oct/2000:13:55:36 -0700",
+            "10/Oct/2000:13:55:36 -07",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rfc5322_zones() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected, format) in &[
+            // AM/PM is never a zone
+            (
+                "8 Nov 2021 05:32 PM",
+                1636392720,
+                Format::Strftime("%d %B %Y %I:%M %P"),
+            ),
+            (
+                "12 Feb 2006 07:17 PM",
+                1139771820,
+                Format::Strftime("%d %B %Y %I:%M %P"),
+            ),
+            (
+                "7 oct 1970 10:09 pm",
+                24185340,
+                Format::Strftime("%d %B %Y %I:%M %P"),
+            ),
+            ("(day) 8 Nov 2021 00:32 EDT", 1636345920, Format::Rfc5322),
+            ("(day) Mon, 8 Nov 2021 00:32 A", 1636331520, Format::Rfc5322),
+        ] {
+            let (dt, parsed_format) = parse_with_format(input)?;
+            assert_eq!(*expected, dt.timestamp(), "{input}");
+            assert_eq!(*format, parsed_format, "{input}");
+        }
+        for input in &[
+            // unknown zones are only `-0000` when parsing email dates
+            "8 Nov 2021 00:32 XYZ",
+            // the day-of-week must match the date
+            "Tue, 8 Nov 2021 00:32:45 +0000",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rfc850_two_digit_years() -> Result<(), Box<dyn std::error::Error>> {
+        // resolved to 1970 through 2069 rather than relative to the current time
+        for (input, expected) in &[
+            ("Sunday, 06-Nov-94 08:49:37 GMT", 784111777),
+            ("Sunday, 08-Nov-70 00:32:45 GMT", 26872365),
+            ("Friday, 08-Nov-69 00:32:45 GMT", 3151096365),
+        ] {
+            let (dt, format) = parse_with_format(input)?;
+            assert_eq!(*expected, dt.timestamp(), "{input}");
+            assert_eq!(Format::HttpDate, format, "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn git_and_mercurial() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected, offset, format) in &[
+            (
+                "Mon Nov 8 00:32:45 2021 +0000",
+                1636331565,
+                0,
+                Format::Strftime("%a %b %e %H:%M:%S %Y %z"),
+            ),
+            (
+                "Mon Nov 08 01:32:45 2021 +0100",
+                1636331565,
+                3600,
+                Format::Strftime("%a %b %e %H:%M:%S %Y %z"),
+            ),
+            ("1636331565 +0100", 1636331565, 3600, Format::Git),
+            ("1636331565 -0700", 1636331565, -25200, Format::Git),
+            ("@1636331565", 1636331565, 0, Format::Git),
+            ("@1636331565 +0100", 1636331565, 3600, Format::Git),
+            ("1636331565 -3600", 1636331565, 3600, Format::Mercurial),
+            ("1636331565 25200", 1636331565, -25200, Format::Mercurial),
+            ("1636331565 0", 1636331565, 0, Format::Mercurial),
+            (
+                "2021-11-08 01:32:45 +0100",
+                1636331565,
+                3600,
+                Format::Iso8601,
+            ),
+        ] {
+            let (dt, parsed_format) = parse_with_format(input)?;
+            assert_eq!(*expected, dt.timestamp(), "{input}");
+            assert_eq!(*offset, dt.offset().local_minus_utc(), "{input}");
+            assert_eq!(*format, parsed_format, "{input}");
+        }
+        for input in &[
+            "@",
+            "@abc",
+            "@1636331565 +01",
+            "1636331565 +2500",
+            "1636331565 -",
+            // pairs of numbers that aren't a plausible timestamp and offset
+            "1 2",
+            "2021 11",
+            "1636331565 200",
+            "1636331565 -50460",
+            "1 +0000",
+            "2021 +0100",
+            "12 -0500",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+        let (dt, rest) = parse_prefix("1636331565 200 GET /")?;
+        assert_eq!(0, dt.offset().local_minus_utc());
+        assert_eq!(" 200 GET /", rest);
+        Ok(())
+    }
+
+    #[test]
+    fn japanese() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("令和3年11月8日(月) 午前0時32分", 1636331520),
+            ("2021年11月8日 0時32分45秒", 1636331565),
+            ("R3.11.8", 1636329600),
+            ("2021年11月8日(月)", 1636329600),
+        ] {
+            let (dt, format) = parse_with_format(input)?;
+            assert_eq!(*expected, dt.timestamp(), "{input}");
+            assert_eq!(Format::Japanese, format, "{input}");
+        }
+        assert!(parse("平成31年5月1日").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn prefix() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected, remainder) in &[
+            // fast path
+            (
+                "2021-11-08T00:32:45.426Z INFO starting",
+                1636331565426000000,
+                " INFO starting",
+            ),
+            ("2021-11-08T00:32:45+01:00|", 1636327965000000000, "|"),
+            (
+                "2021-11-08 00:32:45 INFO starting",
+                1636331565000000000,
+                " INFO starting",
+            ),
+            (
+                "2021-11-08 00:32:45: starting",
+                1636331565000000000,
+                ": starting",
+            ),
+            ("2021-11-08 00:32:45", 1636331565000000000, ""),
+            // trailing timezone abbreviations, offsets and AM/PM
+            (
+                "2021-11-08 00:32:45 PST starting",
+                1636360365000000000,
+                " starting",
+            ),
+            (
+                "2021-11-08 00:32:45 +0100 starting",
+                1636327965000000000,
+                " starting",
+            ),
+            (
+                "2021-11-08 12:32:45 AM starting",
+                1636331565000000000,
+                " starting",
+            ),
+            // bracketed
+            (
+                "[2021-11-08 00:32:45] starting",
+                1636331565000000000,
+                " starting",
+            ),
+            ("[Mon, 08 Nov 2021 00:32:45 +0000]", 1636331565000000000, ""),
+            // other formats
+            (
+                "Mon, 08 Nov 2021 00:32:45 +0000 starting",
+                1636331565000000000,
+                " starting",
+            ),
+            (
+                "May 8, 2009 5:57:51 PM starting",
+                1241805471000000000,
+                " starting",
+            ),
+            ("1636331565 starting", 1636331565000000000, " starting"),
+        ] {
+            let (dt, rest) = parse_prefix(input)?;
+            assert_eq!(*expected, dt.timestamp_nanos_opt().unwrap(), "{input}");
+            assert_eq!(*remainder, rest, "{input}");
+        }
+        for input in &[
+            "",
+            "starting",
+            "[2021-11-08 00:32:45 starting",
+            "2021-11-08T",
+        ] {
+            assert!(parse_prefix(input).is_err(), "{input}");
+        }
+        Ok(())
+    }
+
     #[test]
     fn misc_timestamps() -> Result<(), Box<dyn std::error::Error>> {
         for (input, expected) in &[
@@ -519,6 +1386,26 @@ mod tests {
             ("2014年04月08日11时25分18秒", 1396956318000000000),
             // chinese yyyy mm dd
             ("2014年04月08日", 1396915200000000000),
+            // http-date
+            ("Sunday, 06-Nov-94 08:49:37 GMT", 784111777000000000),
+            ("Sun Nov  6 08:49:37 1994", 784111777000000000),
+            ("Mon, 08-Nov-2021 00:32:45 GMT", 1636331565000000000),
+            // sql
+            ("TIMESTAMP '2021-11-08 00:32:45'", 1636331565000000000),
+            ("Nov  8 2021 12:32AM", 1636331520000000000),
+            ("08-NOV-21 12.32.45.000000 AM", 1636331565000000000),
+            ("08NOV2021:00:32:45", 1636331565000000000),
+            // yaml
+            ("2001-12-14t21:59:43.10-05:00", 1008385183100000000),
+            ("2001-12-14 21:59:43.10 -5", 1008385183100000000),
+            // rfc 5322 obsolete syntax
+            ("Mon, 8 Nov 2021 00:32:45 -0700 (PDT)", 1636356765000000000),
+            ("8 Nov 21 00:32 EDT", 1636345920000000000),
+            // common log format
+            ("10/Oct/2000:13:55:36 -0700", 971211336000000000),
+            ("[10/Oct/2000:13:55:36 -0700]", 971211336000000000),
+            ("08/Nov/2021:00:32:45 +0000", 1636331565000000000),
+            ("[08/Nov/2021:00:32:45.426 +0100]", 1636327965426000000),
             // timezone abbreviations
             ("2017-11-25 13:31:15 PST", 1511645475000000000),
             ("2014-12-16 06:20:00 GMT", 1418710800000000000),
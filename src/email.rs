@@ -0,0 +1,254 @@
+//! Email date parsing functions
+//!
+//! Implements the complete RFC 5322 date-time grammar, including the obsolete syntax of section
+//! 4.3 which archived mail commonly contains but chrono's strict RFC 2822 parser rejects:
+//!
+//! - comments and folding whitespace eg. `Mon, 8 Nov 2021 00:32:45 -0700 (PDT)`
+//! - missing seconds eg. `8 Nov 2021 00:32 +0000`
+//! - two and three digit years eg. `8 Nov 21 00:32:45 +0000` or `8 Nov 121 00:32:45 +0000`
+//! - named and military zones eg. `EDT` or `Z`
+use crate::errors::Error;
+use chrono::{
+    DateTime, Datelike, FixedOffset, Month, NaiveDate, NaiveTime, Offset, TimeZone, Utc, Weekday,
+};
+
+/// Attempts to parse the provided RFC 5322 date-time string into a `DateTime`\<`FixedOffset`\>.
+///
+/// As required by RFC 5322, military zones and any unknown alphabetic zones, other than `AM` and
+/// `PM`, are treated as `-0000` ie. UTC.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported date-time is provided.
+#[inline]
+pub fn parse(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    parse_with_zones(s, true)
+}
+
+// Used by `datetime::parse` where an unknown zone is more likely to be something other than a
+// zone, so only the numeric, RFC 5322 named and military zones are accepted.
+pub(crate) fn parse_known_zones(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    parse_with_zones(s, false)
+}
+
+fn parse_with_zones(s: &str, unknown_zones: bool) -> Result<DateTime<FixedOffset>, Error> {
+    // cheaply reject other formats before allocating
+    if !has_email_shape(s) {
+        return Err(Error::InvalidDateTime);
+    }
+    let s = normalize(s)?;
+    let mut tokens = s.split_whitespace().peekable();
+
+    // optional day-of-week, the comma is required by the grammar but often omitted
+    let weekday = tokens
+        .peek()
+        .filter(|t| t.len() == 3)
+        .and_then(|t| t.parse::<Weekday>().ok());
+    if weekday.is_some() {
+        tokens.next();
+        tokens.next_if_eq(&",");
+    }
+    let (Some(day), Some(month), Some(year), Some(time), Some(zone), None) = (
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+        tokens.next(),
+    ) else {
+        return Err(Error::InvalidDateTime);
+    };
+
+    let day = parse_number(day, 1..=2)?;
+    let month = match month.parse::<Month>() {
+        Ok(m) if month.len() == 3 => m.number_from_month(),
+        _ => return Err(Error::InvalidDateTime),
+    };
+    let year = match (year.len(), parse_number(year, 2..=9)?) {
+        (2, y) if y < 50 => y + 2000,
+        (2 | 3, y) => y + 1900,
+        (_, y) => y,
+    };
+    let date = NaiveDate::from_ymd_opt(
+        year.try_into().map_err(|_| Error::InvalidDateTime)?,
+        month,
+        day,
+    )
+    .ok_or(Error::InvalidDateTime)?;
+    if weekday.is_some_and(|weekday| weekday != date.weekday()) {
+        return Err(Error::InvalidDateTime);
+    }
+
+    let mut parts = time.split(':');
+    let (Some(hour), Some(minute), second, None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Error::InvalidDateTime);
+    };
+    let time = NaiveTime::from_hms_opt(
+        parse_number(hour, 1..=2)?,
+        parse_number(minute, 2..=2)?,
+        second.map_or(Ok(0), |s| parse_number(s, 2..=2))?,
+    )
+    .ok_or(Error::InvalidDateTime)?;
+
+    parse_zone(zone, unknown_zones)?
+        .from_local_datetime(&date.and_time(time))
+        .single()
+        .ok_or(Error::InvalidDateTime)
+}
+
+fn parse_number(s: &str, digits: std::ops::RangeInclusive<usize>) -> Result<u32, Error> {
+    if !digits.contains(&s.len()) || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    s.parse().map_err(|_| Error::InvalidDateTime)
+}
+
+// starts with a comment, day-of-week or a one or two digit day.
+fn has_email_shape(s: &str) -> bool {
+    let s = s.trim_start();
+    match s.as_bytes().first() {
+        Some(b'(') => true,
+        Some(c) if c.is_ascii_alphabetic() => {
+            s.get(..3).is_some_and(|w| w.parse::<Weekday>().is_ok())
+        }
+        Some(c) if c.is_ascii_digit() => {
+            let len = s.bytes().take_while(u8::is_ascii_digit).count();
+            len <= 2
+                && matches!(
+                    s.as_bytes().get(len),
+                    Some(b' ' | b'\t' | b'\r' | b'\n' | b'(')
+                )
+        }
+        _ => false,
+    }
+}
+
+fn parse_zone(zone: &str, unknown_zones: bool) -> Result<FixedOffset, Error> {
+    let b = zone.as_bytes();
+    match b.first() {
+        Some(b'+' | b'-') if b.len() == 5 => {
+            let offset = parse_number(&zone[1..], 4..=4)?;
+            let (hours, minutes) = (offset / 100, offset % 100);
+            if minutes > 59 {
+                return Err(Error::InvalidDateTime);
+            }
+            let secs =
+                i32::try_from(hours * 3600 + minutes * 60).map_err(|_| Error::InvalidDateTime)?;
+            let secs = if b[0] == b'-' { -secs } else { secs };
+            FixedOffset::east_opt(secs).ok_or(Error::InvalidDateTime)
+        }
+        // military zones, with the exception of J, were incorrectly specified by RFC 822
+        Some(c) if b.len() == 1 && c.is_ascii_alphabetic() && !c.eq_ignore_ascii_case(&b'J') => {
+            Ok(Utc.fix())
+        }
+        Some(_) if b.len() > 1 && b.iter().all(u8::is_ascii_alphabetic) => {
+            let hours = match zone.to_ascii_uppercase().as_str() {
+                "UT" | "GMT" => 0,
+                "EDT" => -4,
+                "EST" | "CDT" => -5,
+                "CST" | "MDT" => -6,
+                "MST" | "PDT" => -7,
+                "PST" => -8,
+                "AM" | "PM" => return Err(Error::InvalidDateTime),
+                _ if unknown_zones => 0,
+                _ => return Err(Error::InvalidDateTime),
+            };
+            FixedOffset::east_opt(hours * 3600).ok_or(Error::InvalidDateTime)
+        }
+        _ => Err(Error::InvalidDateTime),
+    }
+}
+
+// Replaces comments, which may be nested and contain quoted-pairs, with whitespace and removes
+// the whitespace the obsolete syntax allows around the time-of-day separators eg. `00 : 32`.
+fn normalize(s: &str) -> Result<String, Error> {
+    let mut out = String::with_capacity(s.len());
+    let mut depth = 0_usize;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1).ok_or(Error::InvalidDateTime)?;
+                out.push(' ');
+            }
+            '\\' if depth > 0 => {
+                chars.next();
+            }
+            _ if depth > 0 => {}
+            ',' => out.push_str(" , "),
+            _ => out.push(c),
+        }
+    }
+    if depth > 0 {
+        return Err(Error::InvalidDateTime);
+    }
+    Ok(out.split(':').map(str::trim).collect::<Vec<_>>().join(":"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn email_dates() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("Mon, 08 Nov 2021 00:32:45 +0000", 1636331565),
+            // comments and folding whitespace
+            ("Mon, 08 Nov 2021 00:32:45 -0700 (PDT)", 1636356765),
+            (
+                "Mon, 8 Nov 2021 00:32:45 +0000 (UTC (Coordinated Universal Time))",
+                1636331565,
+            ),
+            ("Mon,\r\n 8 Nov 2021\r\n\t00:32:45 +0000", 1636331565),
+            (
+                "(day) Mon , 8 (month) Nov 2021 00 : 32 : 45 GMT",
+                1636331565,
+            ),
+            ("Mon, 8 Nov 2021 00:32:45 (\\) escaped) +0000", 1636331565),
+            // optional day of week and missing comma
+            ("8 Nov 2021 00:32:45 +0000", 1636331565),
+            ("Mon 8 Nov 2021 00:32:45 +0000", 1636331565),
+            // missing seconds
+            ("Mon, 8 Nov 2021 00:32 +0000", 1636331520),
+            // two and three digit years
+            ("Mon, 8 Nov 21 00:32:45 +0000", 1636331565),
+            ("Tue, 8 Nov 94 08:49:37 +0000", 784284577),
+            ("Mon, 8 Nov 121 00:32:45 +0000", 1636331565),
+            // named zones
+            ("Mon, 8 Nov 2021 00:32:45 EDT", 1636345965),
+            ("Mon, 8 Nov 2021 00:32:45 UT", 1636331565),
+            ("Mon, 8 Nov 2021 00:32:45 pst", 1636360365),
+            ("Mon, 8 Nov 2021 00:32:45 XYZ", 1636331565),
+            // military zones
+            ("Mon, 8 Nov 2021 00:32:45 Z", 1636331565),
+            ("Mon, 8 Nov 2021 00:32:45 A", 1636331565),
+        ] {
+            assert_eq!(*expected, parse(input)?.timestamp(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "Mon, 8 Nov 2021 00:32:45",
+            "Mon, 8 Nov 2021 00:32:45 J",
+            "Mon, 8 Nov 2021 00:32:45 +000",
+            "Mon, 8 Nov 2021 00:32:45 +0000 (unterminated",
+            "Mon, 8 November 2021 00:32:45 +0000",
+            "Mon, 32 Nov 2021 00:32:45 +0000",
+            "Mon, 8 Nov 2021 00:32:45.123 +0000",
+            "Mon, 8 Nov 2021 00:32:45 +0000 extra",
+            "Tue, 8 Nov 2021 00:32:45 +0000",
+            "8 Nov 2021 05:32 PM",
+            "8 Nov 2021 05:32 am",
+            "2021-11-08 00:32:45 +0000",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
@@ -2,10 +2,14 @@
 use thiserror::Error;
 
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     #[error("Invalid Date")]
     InvalidDate,
 
     #[error("Invalid DateTime")]
     InvalidDateTime,
+
+    #[error("Invalid Time")]
+    InvalidTime,
 }
@@ -0,0 +1,65 @@
+//! EXIF date and time parsing functions
+//!
+//! EXIF stores date and time tags such as `DateTimeOriginal` with colons in the date eg.
+//! `2021:11:08 00:32:45`, and since EXIF 2.31 their offset from UTC in a separate tag such as
+//! `OffsetTimeOriginal` eg. `+01:00`.
+use crate::datetime::parse_numeric_offset;
+use crate::errors::Error;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeZone, Utc};
+
+/// Attempts to parse the provided EXIF date and time, along with its optional offset tag, into a
+/// `DateTime`\<`FixedOffset`\>.
+///
+/// Tags may contain their trailing NUL, and offsets that are missing or unknown ie. blank are
+/// assumed to be UTC.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unknown date and time, or an invalid offset, is provided.
+pub fn parse(date_time: &str, offset_time: Option<&str>) -> Result<DateTime<FixedOffset>, Error> {
+    let ndt =
+        NaiveDateTime::parse_from_str(date_time.trim_end_matches('\0'), "%Y:%m:%d %H:%M:%S%.f")
+            .map_err(|_| Error::InvalidDateTime)?;
+    let offset = match offset_time.map(|tz| tz.trim_end_matches('\0')) {
+        Some(tz) if !tz.trim_matches([' ', ':']).is_empty() => parse_numeric_offset(tz)?,
+        _ => Utc.fix(),
+    };
+    offset
+        .from_local_datetime(&ndt)
+        .single()
+        .ok_or(Error::InvalidDateTime)
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exif() -> Result<(), Box<dyn std::error::Error>> {
+        for (date_time, offset_time, expected) in &[
+            ("2021:11:08 00:32:45", None, 1636331565),
+            ("2021:11:08 01:32:45", Some("+01:00"), 1636331565),
+            ("2021:11:07 16:32:45\0", Some("-08:00\0"), 1636331565),
+            ("2021:11:08 00:32:45", Some("   :  "), 1636331565),
+        ] {
+            assert_eq!(
+                *expected,
+                parse(date_time, *offset_time)?.timestamp(),
+                "{date_time}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for (date_time, offset_time) in &[
+            ("", None),
+            ("    :  :     :  :  ", None),
+            ("0000:00:00 00:00:00", None),
+            ("2021:11:08 00:32:45", Some("+1")),
+        ] {
+            assert!(parse(date_time, *offset_time).is_err(), "{date_time}");
+        }
+    }
+}
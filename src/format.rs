@@ -0,0 +1,41 @@
+//! Recognized formats
+
+/// The format a `Date` or `DateTime` string was recognized as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+    /// Unix timestamp in seconds, milliseconds, microseconds or nanoseconds eg. `1636331565`.
+    UnixTimestamp,
+    /// RFC 3339 eg. `2021-11-08T00:32:45Z`.
+    Rfc3339,
+    /// RFC 2822 eg. `Mon, 08 Nov 2021 00:32:45 +0000`.
+    Rfc2822,
+    /// RFC 5322, including the obsolete syntax eg. `Mon, 8 Nov 2021 00:32:45 -0700 (PDT)`.
+    Rfc5322,
+    /// RFC 9110 HTTP-date, including the obsolete RFC 850 and asctime formats eg.
+    /// `Sunday, 06-Nov-94 08:49:37 GMT`.
+    HttpDate,
+    /// WCF JSON, with an optional offset, eg. `/Date(1636331565426+0100)/`.
+    WcfJson,
+    /// ISO 8601 eg. `2021-11-08T00:32:45+00:00`.
+    Iso8601,
+    /// SQL literal or vendor specific database output eg. `TIMESTAMP '2021-11-08 00:32:45'` or
+    /// `08-NOV-21 12.32.45.000000 AM`.
+    Sql,
+    /// ASN.1 `UTCTime` or `GeneralizedTime` eg. `211108003245Z` or `20211108003245.426Z`.
+    Asn1,
+    /// YAML 1.1 timestamp eg. `2001-12-14 21:59:43.10 -5`.
+    Yaml,
+    /// Git's raw unix timestamp and offset eg. `1636331565 +0100`, or its `@` prefixed unix
+    /// timestamp with an optional offset eg. `@1636331565`.
+    Git,
+    /// Japanese date, with a Gregorian or imperial era year, eg. `2021年11月8日(月)`, `令和3年11月8日`
+    /// or `R3.11.8`.
+    Japanese,
+    /// Mercurial's raw unix timestamp and offset in seconds west of UTC eg. `1636331565 -3600`.
+    Mercurial,
+    /// The contained `strftime` format eg. `%Y-%m-%d %H:%M:%S`.
+    Strftime(&'static str),
+    /// The contained `strftime` format followed by a timezone abbreviation eg. `PST`.
+    TimezoneAbbreviation(&'static str),
+}
@@ -0,0 +1,171 @@
+//! HTTP-date parsing functions
+//!
+//! Implements the RFC 9110 HTTP-date grammar, which recipients must accept in all three of its
+//! formats:
+//!
+//! - IMF-fixdate eg. `Sun, 06 Nov 1994 08:49:37 GMT`
+//! - obsolete RFC 850 eg. `Sunday, 06-Nov-94 08:49:37 GMT`
+//! - obsolete asctime eg. `Sun Nov  6 08:49:37 1994`
+//!
+//! as well as the Netscape cookie `Expires` variant of RFC 850 with a four digit year eg.
+//! `Mon, 08-Nov-2021 00:32:45 GMT`.
+use crate::errors::Error;
+use chrono::{DateTime, Datelike, Month, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use std::time::SystemTime;
+
+/// Attempts to parse the provided HTTP-date string into a `DateTime`\<`Utc`\>.
+///
+/// Two digit years are resolved relative to the current time, see [`parse_with_reference`].
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported HTTP-date format is provided.
+#[inline]
+pub fn parse(s: &str) -> Result<DateTime<Utc>, Error> {
+    parse_with_reference(s, SystemTime::now().into())
+}
+
+/// Attempts to parse the provided HTTP-date string into a `DateTime`\<`Utc`\>.
+///
+/// Two digit years that would appear to be more than 50 years after the `reference` are
+/// interpreted as the most recent year in the past with the same last two digits, as required by
+/// RFC 9110.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported HTTP-date format is provided.
+pub fn parse_with_reference(s: &str, reference: DateTime<Utc>) -> Result<DateTime<Utc>, Error> {
+    parse_imf_fixdate(s)
+        .or_else(|_| parse_asctime(s))
+        .or_else(|_| parse_rfc850(s, reference))
+        .map(|dt| dt.and_utc())
+}
+
+// Used by `datetime::parse`, whose results shouldn't depend on when it's run, resolving two digit
+// years as chrono's `%y` does to 1970 through 2069.
+pub(crate) fn parse_fixed_window(s: &str) -> Result<DateTime<Utc>, Error> {
+    // the window ends 50 years after the reference
+    let reference = NaiveDate::from_ymd_opt(2019, 1, 1).ok_or(Error::InvalidDateTime)?;
+    parse_with_reference(s, reference.and_time(NaiveTime::MIN).and_utc())
+}
+
+// eg. Sun, 06 Nov 1994 08:49:37 GMT
+fn parse_imf_fixdate(s: &str) -> Result<NaiveDateTime, Error> {
+    NaiveDateTime::parse_from_str(s, "%a, %d %b %Y %H:%M:%S GMT")
+        .map_err(|_| Error::InvalidDateTime)
+}
+
+// eg. Sun Nov  6 08:49:37 1994
+fn parse_asctime(s: &str) -> Result<NaiveDateTime, Error> {
+    NaiveDateTime::parse_from_str(s, "%a %b %e %H:%M:%S %Y").map_err(|_| Error::InvalidDateTime)
+}
+
+// eg. Sunday, 06-Nov-94 08:49:37 GMT
+fn parse_rfc850(s: &str, reference: DateTime<Utc>) -> Result<NaiveDateTime, Error> {
+    let (weekday, rest) = s.split_once(", ").ok_or(Error::InvalidDateTime)?;
+    let (date, rest) = rest.split_once(' ').ok_or(Error::InvalidDateTime)?;
+    let time = rest.strip_suffix(" GMT").ok_or(Error::InvalidDateTime)?;
+
+    let mut parts = date.splitn(3, '-');
+    let (Some(day), Some(month), Some(year)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(Error::InvalidDateTime);
+    };
+    if day.len() != 2 || !matches!(year.len(), 2 | 4) {
+        return Err(Error::InvalidDateTime);
+    }
+    let day: u32 = day.parse().map_err(|_| Error::InvalidDateTime)?;
+    let month = month_from_abbreviation(month)?;
+    let mut year: i32 = year.parse().map_err(|_| Error::InvalidDateTime)?;
+    if year < 100 {
+        year += reference.year() - reference.year().rem_euclid(100);
+        if year > reference.year() + 50 {
+            year -= 100;
+        }
+    }
+
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or(Error::InvalidDateTime)?;
+    if weekday.parse::<Weekday>().ok() != Some(date.weekday()) {
+        return Err(Error::InvalidDateTime);
+    }
+    let time = NaiveTime::parse_from_str(time, "%H:%M:%S").map_err(|_| Error::InvalidDateTime)?;
+    Ok(date.and_time(time))
+}
+
+fn month_from_abbreviation(s: &str) -> Result<u32, Error> {
+    match s.parse::<Month>() {
+        Ok(month) if s.len() == 3 => Ok(month.number_from_month()),
+        _ => Err(Error::InvalidDateTime),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn http_dates() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse_utc("2021-11-08T00:00:00Z")?;
+        for (input, expected) in &[
+            // IMF-fixdate
+            ("Sun, 06 Nov 1994 08:49:37 GMT", 784111777),
+            // RFC 850
+            ("Sunday, 06-Nov-94 08:49:37 GMT", 784111777),
+            ("Monday, 08-Nov-21 00:32:45 GMT", 1636331565),
+            // asctime
+            ("Sun Nov  6 08:49:37 1994", 784111777),
+            ("Wed Nov 16 08:49:37 1994", 784975777),
+            // cookie Expires
+            ("Mon, 08-Nov-2021 00:32:45 GMT", 1636331565),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_with_reference(input, reference)?.timestamp(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn two_digit_years() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse_utc("2021-11-08T00:00:00Z")?;
+        for (input, expected) in &[
+            ("Monday, 08-Nov-21 00:32:45 GMT", 2021),
+            ("Wednesday, 08-Nov-72 00:32:45 GMT", 1972),
+            ("Sunday, 08-Nov-71 00:32:45 GMT", 2071),
+            ("Saturday, 08-Nov-70 00:32:45 GMT", 2070),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_with_reference(input, reference)?.year(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_window() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("Sunday, 06-Nov-94 08:49:37 GMT", 1994),
+            ("Sunday, 08-Nov-70 00:32:45 GMT", 1970),
+            ("Friday, 08-Nov-69 00:32:45 GMT", 2069),
+            ("Mon, 08-Nov-2021 00:32:45 GMT", 2021),
+        ] {
+            assert_eq!(*expected, parse_fixed_window(input)?.year(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "Sun, 06 Nov 1994 08:49:37 PST",
+            "Monday, 06-Nov-94 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37",
+            "Sunday, 6-Nov-94 08:49:37 GMT",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
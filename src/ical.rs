@@ -0,0 +1,245 @@
+//! iCalendar date and time parsing functions
+//!
+//! Implements the RFC 5545 `DATE` and `DATE-TIME` value types, along with the `VALUE` and `TZID`
+//! parameters of properties such as `DTSTART`:
+//!
+//! - date eg. `DTSTART;VALUE=DATE:20211108`
+//! - UTC date-time eg. `DTSTART:20211108T003245Z`
+//! - zoned date-time eg. `DTSTART;TZID=America/New_York:20211108T003245`
+//! - floating date-time eg. `DTSTART:20211108T003245`
+//!
+//! Resolving a `TZID` requires the `tz` feature, without it zoned date-times are returned as
+//! floating date-times.
+//!
+//! ## Example
+//! ```rust
+//! use anydate::ical::{parse, Value};
+//! use chrono::NaiveDate;
+//!
+//! assert_eq!(
+//!     Value::Date(NaiveDate::from_ymd_opt(2021, 11, 8).unwrap()),
+//!     parse("DTSTART;VALUE=DATE:20211108").unwrap()
+//! );
+//! assert!(matches!(parse("DTSTART:20211108T003245Z").unwrap(), Value::Utc(_)));
+//! ```
+use crate::datetime::parse_iso_basic;
+use crate::errors::Error;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+#[cfg(feature = "tz")]
+use chrono::{LocalResult, Offset, TimeDelta};
+#[cfg(feature = "tz")]
+use chrono_tz::Tz;
+
+/// A parsed iCalendar `DATE` or `DATE-TIME` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Value {
+    /// A date eg. `20211108`, such as the start of an all day event.
+    Date(NaiveDate),
+    /// A date-time in UTC eg. `20211108T003245Z`.
+    Utc(DateTime<Utc>),
+    /// A date-time in the timezone of its `TZID` parameter.
+    #[cfg(feature = "tz")]
+    Zoned(DateTime<Tz>),
+    /// A date-time without a timezone eg. `20211108T003245`, the same local time in every
+    /// timezone.
+    Floating(NaiveDateTime),
+}
+
+/// Attempts to parse the provided iCalendar property eg.
+/// `DTSTART;TZID=America/New_York:20211108T003245`, or only its value eg. `20211108T003245Z`.
+///
+/// A `VALUE` parameter, when present, must match the kind of value.
+///
+/// # Errors
+/// Will return `Err` when an invalid date or date-time is provided, or it doesn't match the
+/// `VALUE` parameter.
+pub fn parse(property: &str) -> Result<Value, Error> {
+    let property = property.trim_end_matches(['\r', '\n']);
+    let Some(idx) = find_unquoted(property, b':') else {
+        return parse_value(property, None);
+    };
+    let (mut params, value) = (&property[..idx], &property[idx + 1..]);
+    let mut kind = None;
+    let mut tzid = None;
+    // the property name comes before the first parameter
+    while let Some(idx) = find_unquoted(params, b';') {
+        params = &params[idx + 1..];
+        let param = &params[..find_unquoted(params, b';').unwrap_or(params.len())];
+        let (name, param_value) = param.split_once('=').ok_or(Error::InvalidDateTime)?;
+        let param_value = param_value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(param_value);
+        if name.eq_ignore_ascii_case("VALUE") {
+            kind = Some(param_value);
+        } else if name.eq_ignore_ascii_case("TZID") {
+            tzid = Some(param_value);
+        }
+    }
+
+    let value = parse_value(value, tzid)?;
+    match kind {
+        Some(kind) if kind.eq_ignore_ascii_case("DATE") => matches!(value, Value::Date(_))
+            .then_some(value)
+            .ok_or(Error::InvalidDate),
+        Some(kind) if kind.eq_ignore_ascii_case("DATE-TIME") => (!matches!(value, Value::Date(_)))
+            .then_some(value)
+            .ok_or(Error::InvalidDateTime),
+        Some(_) => Err(Error::InvalidDateTime),
+        None => Ok(value),
+    }
+}
+
+/// Attempts to parse the provided iCalendar `DATE` or `DATE-TIME` value, resolving date-times
+/// without a zone in the optional `tzid` timezone.
+///
+/// A `tzid` is ignored for UTC date-times, and unknown `tzid`s, such as those only defined by a
+/// `VTIMEZONE` component, result in a floating date-time. Times that don't exist because they're
+/// skipped by a daylight saving transition use the offset from before the transition, and times
+/// that occur twice use the first.
+///
+/// # Errors
+/// Will return `Err` when an invalid date or date-time is provided.
+pub fn parse_value(value: &str, tzid: Option<&str>) -> Result<Value, Error> {
+    if value.len() == 8 && value.bytes().all(|b| b.is_ascii_digit()) {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .map(Value::Date)
+            .map_err(|_| Error::InvalidDate);
+    }
+    let (ndt, offset) = parse_iso_basic(value)?;
+    if let Some(offset) = offset {
+        return offset
+            .from_local_datetime(&ndt)
+            .single()
+            .map(|dt| Value::Utc(dt.with_timezone(&Utc)))
+            .ok_or(Error::InvalidDateTime);
+    }
+    #[cfg(feature = "tz")]
+    if let Some(dt) = tzid
+        // a leading `/` marks a globally unique identifier
+        .and_then(|tzid| tzid.trim_start_matches('/').parse::<Tz>().ok())
+        .and_then(|tz| resolve(&ndt, tz))
+    {
+        return Ok(Value::Zoned(dt));
+    }
+    #[cfg(not(feature = "tz"))]
+    let _ = tzid;
+    Ok(Value::Floating(ndt))
+}
+
+#[cfg(feature = "tz")]
+fn resolve(ndt: &NaiveDateTime, tz: Tz) -> Option<DateTime<Tz>> {
+    match tz.from_local_datetime(ndt) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Some(dt),
+        LocalResult::None => {
+            let before = ndt.checked_sub_signed(TimeDelta::try_hours(3)?)?;
+            let offset = tz.offset_from_local_datetime(&before).earliest()?.fix();
+            Some(tz.from_utc_datetime(&(*ndt - offset)))
+        }
+    }
+}
+
+// finds the first `sep` outside of a double quoted parameter value.
+fn find_unquoted(s: &str, sep: u8) -> Option<usize> {
+    let mut quoted = false;
+    s.bytes().position(|b| {
+        if b == b'"' {
+            quoted = !quoted;
+        }
+        !quoted && b == sep
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() -> Result<(), Box<dyn std::error::Error>> {
+        for input in &["DTSTART;VALUE=DATE:20211108", "DTEND:20211108", "20211108"] {
+            assert_eq!(
+                Value::Date(NaiveDate::from_ymd_opt(2021, 11, 8).unwrap()),
+                parse(input)?,
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn date_times() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("DTSTART:20211108T003245Z", 1636331565),
+            ("DTSTAMP;VALUE=DATE-TIME:20211108T003245Z\r\n", 1636331565),
+            ("DTSTART;TZID=America/New_York:20211108T003245Z", 1636331565),
+            ("20211108T003245Z", 1636331565),
+        ] {
+            let Value::Utc(dt) = parse(input)? else {
+                panic!("{input}");
+            };
+            assert_eq!(*expected, dt.timestamp(), "{input}");
+        }
+
+        let expected = NaiveDate::from_ymd_opt(2021, 11, 8)
+            .unwrap()
+            .and_hms_opt(0, 32, 45)
+            .unwrap();
+        assert_eq!(Value::Floating(expected), parse("DTSTART:20211108T003245")?);
+        assert_eq!(
+            Value::Floating(expected),
+            parse("DTSTART;TZID=/example.com/Custom:20211108T003245")?
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "tz")]
+    #[test]
+    fn zoned() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("DTSTART;TZID=America/New_York:20211108T003245", 1636349565),
+            (
+                "DTSTART;X-NAME=\"a;b:c\";TZID=\"Europe/Paris\":20211108T003245",
+                1636327965,
+            ),
+            ("DTSTART;TZID=/Europe/Paris:20211108T003245", 1636327965),
+            // skipped and repeated by daylight saving transitions
+            ("DTSTART;TZID=America/New_York:20210314T023000", 1615707000),
+            ("DTSTART;TZID=America/New_York:20211107T013000", 1636263000),
+        ] {
+            let Value::Zoned(dt) = parse(input)? else {
+                panic!("{input}");
+            };
+            assert_eq!(*expected, dt.timestamp(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "tz"))]
+    #[test]
+    fn zoned() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(matches!(
+            parse("DTSTART;TZID=America/New_York:20211108T003245")?,
+            Value::Floating(_)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "DTSTART:",
+            "DTSTART;VALUE=DATE:20211108T003245Z",
+            "DTSTART;VALUE=DATE-TIME:20211108",
+            "DTSTART;VALUE=PERIOD:20211108T003245Z/PT1H",
+            "DTSTART;TZID:20211108T003245",
+            "DTSTART:20211308",
+            "DTSTART:20211108T253245",
+            "DTSTART:2021-11-08T00:32:45Z",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
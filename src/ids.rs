@@ -0,0 +1,247 @@
+//! Identifier timestamp extraction functions
+//!
+//! Many identifiers embed the time they were created:
+//!
+//! - UUID versions 1, 6 and 7 eg. `017cfcf6-3172-7cc3-98c4-dc0c0c07398f`
+//! - ULIDs eg. `01FKYFCCBJARZ3NDEKTSV4RRFF`
+//! - `MongoDB` `ObjectId`s eg. `6188702de5d8a1b2c3d4e5f6`
+//! - Snowflake IDs, relative to a configurable epoch, eg. `1457506340648792121`
+//!
+//! ## Example
+//! ```rust
+//! use anydate::ids;
+//!
+//! let dt = ids::parse("017cfcf6-3172-7cc3-98c4-dc0c0c07398f").unwrap();
+//! assert_eq!(1636331565426, dt.timestamp_millis());
+//!
+//! let dt = ids::parse_snowflake("175928847299117063", ids::DISCORD_EPOCH).unwrap();
+//! assert_eq!(1462015105796, dt.timestamp_millis());
+//! ```
+use crate::errors::Error;
+use chrono::{DateTime, Utc};
+
+/// Twitter's Snowflake epoch, in milliseconds since the unix epoch.
+pub const TWITTER_EPOCH: i64 = 1_288_834_974_657;
+
+/// Discord's Snowflake epoch, in milliseconds since the unix epoch.
+pub const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
+// 100-nanosecond intervals between the UUID epoch, 1582-10-15, and the unix epoch.
+const UUID_EPOCH_OFFSET: i64 = 0x01B2_1DD2_1381_4000;
+
+// Crockford's base32 alphabet used by ULIDs.
+const CROCKFORD: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Attempts to extract the creation time from the provided UUID, ULID or `ObjectId`.
+///
+/// Snowflake IDs are plain numbers relative to an application specific epoch, see
+/// [`parse_snowflake`].
+///
+/// # Errors
+/// Will return `Err` when the string is not one of the supported identifiers.
+pub fn parse(s: &str) -> Result<DateTime<Utc>, Error> {
+    match s.len() {
+        24 => parse_object_id(s),
+        26 => parse_ulid(s),
+        _ => parse_uuid(s),
+    }
+}
+
+/// Attempts to extract the creation time from the provided version 1, 6 or 7 UUID, optionally
+/// wrapped in braces or prefixed with `urn:uuid:`.
+///
+/// # Errors
+/// Will return `Err` when an invalid UUID, or one of another version, is provided.
+pub fn parse_uuid(s: &str) -> Result<DateTime<Utc>, Error> {
+    let s = s
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .or_else(|| {
+            s.get(..9)
+                .filter(|urn| urn.eq_ignore_ascii_case("urn:uuid:"))
+                .map(|_| &s[9..])
+        })
+        .unwrap_or(s);
+    let hex = match s.len() {
+        32 => s.to_owned(),
+        36 if [8, 13, 18, 23].iter().all(|idx| s.as_bytes()[*idx] == b'-') => s.replace('-', ""),
+        _ => return Err(Error::InvalidDateTime),
+    };
+    if hex.len() != 32
+        || !hex.bytes().all(|b| b.is_ascii_hexdigit())
+        || !matches!(hex.as_bytes()[16], b'8' | b'9' | b'a' | b'b' | b'A' | b'B')
+    {
+        return Err(Error::InvalidDateTime);
+    }
+    match hex.as_bytes()[12] {
+        b'1' => {
+            let ticks = (hex_value(&hex[13..16])? << 48)
+                | (hex_value(&hex[8..12])? << 32)
+                | hex_value(&hex[..8])?;
+            from_uuid_ticks(ticks)
+        }
+        b'6' => {
+            let ticks = (hex_value(&hex[..12])? << 12) | hex_value(&hex[13..16])?;
+            from_uuid_ticks(ticks)
+        }
+        b'7' => from_millis(hex_value(&hex[..12])?),
+        _ => Err(Error::InvalidDateTime),
+    }
+}
+
+/// Attempts to extract the creation time from the provided ULID.
+///
+/// # Errors
+/// Will return `Err` when an invalid ULID is provided.
+pub fn parse_ulid(s: &str) -> Result<DateTime<Utc>, Error> {
+    if s.len() != 26 {
+        return Err(Error::InvalidDateTime);
+    }
+    let mut values = s.bytes().map(|b| {
+        CROCKFORD
+            .iter()
+            .position(|c| c.eq_ignore_ascii_case(&b))
+            .and_then(|value| u64::try_from(value).ok())
+    });
+    // the first 10 characters are the 48 bit timestamp, which can't exceed `7ZZZZZZZZZ`
+    let millis = values
+        .by_ref()
+        .take(10)
+        .try_fold(0, |millis, value| Some(millis << 5 | value?))
+        .ok_or(Error::InvalidDateTime)?;
+    if millis >> 48 != 0 || !values.all(|value| value.is_some()) {
+        return Err(Error::InvalidDateTime);
+    }
+    from_millis(millis)
+}
+
+/// Attempts to extract the creation time, to the second, from the provided `MongoDB` `ObjectId`.
+///
+/// # Errors
+/// Will return `Err` when an invalid `ObjectId` is provided.
+pub fn parse_object_id(s: &str) -> Result<DateTime<Utc>, Error> {
+    if s.len() != 24 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    let secs = hex_value(&s[..8])?;
+    DateTime::from_timestamp(i64::try_from(secs).map_err(|_| Error::InvalidDateTime)?, 0)
+        .ok_or(Error::InvalidDateTime)
+}
+
+/// Attempts to extract the creation time from the provided Snowflake ID, whose timestamp is in
+/// milliseconds since the `epoch`, itself in milliseconds since the unix epoch eg.
+/// [`TWITTER_EPOCH`] or [`DISCORD_EPOCH`].
+///
+/// # Errors
+/// Will return `Err` when an invalid Snowflake ID is provided.
+pub fn parse_snowflake(s: &str, epoch: i64) -> Result<DateTime<Utc>, Error> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    let id: u64 = s.parse().map_err(|_| Error::InvalidDateTime)?;
+    i64::try_from(id >> 22)
+        .ok()
+        .and_then(|millis| millis.checked_add(epoch))
+        .and_then(DateTime::from_timestamp_millis)
+        .ok_or(Error::InvalidDateTime)
+}
+
+// converts 100-nanosecond intervals since the UUID epoch.
+fn from_uuid_ticks(ticks: u64) -> Result<DateTime<Utc>, Error> {
+    let ticks = i64::try_from(ticks).map_err(|_| Error::InvalidDateTime)? - UUID_EPOCH_OFFSET;
+    let nanos = u32::try_from(ticks.rem_euclid(10_000_000) * 100).unwrap_or_default();
+    DateTime::from_timestamp(ticks.div_euclid(10_000_000), nanos).ok_or(Error::InvalidDateTime)
+}
+
+fn from_millis(millis: u64) -> Result<DateTime<Utc>, Error> {
+    i64::try_from(millis)
+        .ok()
+        .and_then(DateTime::from_timestamp_millis)
+        .ok_or(Error::InvalidDateTime)
+}
+
+fn hex_value(hex: &str) -> Result<u64, Error> {
+    // `from_str_radix` would also accept a leading sign
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    u64::from_str_radix(hex, 16).map_err(|_| Error::InvalidDateTime)
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            // uuid v1
+            ("646cd1ea-402b-11ec-bf2a-0242ac130003", 1636331565426737000),
+            ("646CD1EA402B11ECBF2A0242AC130003", 1636331565426737000),
+            (
+                "urn:uuid:646cd1ea-402b-11ec-bf2a-0242ac130003",
+                1636331565426737000,
+            ),
+            // uuid v6
+            ("1ec402b6-46cd-61ea-bf2a-0242ac130003", 1636331565426737000),
+            // uuid v7
+            ("017cfcf6-3172-7cc3-98c4-dc0c0c07398f", 1636331565426000000),
+            (
+                "{017cfcf6-3172-7cc3-98c4-dc0c0c07398f}",
+                1636331565426000000,
+            ),
+            // ulid
+            ("01FKYFCCBJARZ3NDEKTSV4RRFF", 1636331565426000000),
+            ("01fkyfccbjarz3ndektsv4rrff", 1636331565426000000),
+            // object id
+            ("6188702de5d8a1b2c3d4e5f6", 1636331565000000000),
+        ] {
+            assert_eq!(
+                *expected,
+                parse(input)?.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn snowflakes() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, epoch, expected) in &[
+            ("1457506340648792121", TWITTER_EPOCH, 1636331565426),
+            ("907065071190933504", DISCORD_EPOCH, 1636331565426),
+            ("175928847299117063", DISCORD_EPOCH, 1462015105796),
+            ("0", 0, 0),
+        ] {
+            assert_eq!(
+                *expected,
+                parse_snowflake(input, *epoch)?.timestamp_millis(),
+                "{input}"
+            );
+        }
+        assert!(parse_snowflake("", TWITTER_EPOCH).is_err());
+        assert!(parse_snowflake("+1457506340648792121", TWITTER_EPOCH).is_err());
+        assert!(parse_snowflake("99999999999999999999", TWITTER_EPOCH).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            // uuid v4
+            "f47ac10b-58cc-4372-a567-0e02b2c3d479",
+            // non RFC 4122 variant
+            "017cfcf6-3172-7cc3-08c4-dc0c0c07398f",
+            "017cfcf6-3172-7cc3-98c4-dc0c0c07398g",
+            "017cfcf6+3172+7cc3+98c4+dc0c0c07398f",
+            // ulid overflow and excluded letters
+            "81FKYFCCBJARZ3NDEKTSV4RRFF",
+            "01FKYFCCBJARZ3NDEKTSV4RRFU",
+            "6188702de5d8a1b2c3d4e5fz",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Japanese `Date` & `DateTime` parsing functions
+//!
+//! Supports Gregorian and imperial era years, including `元年` for the first year of an era, with
+//! an optional trailing weekday and time:
+//!
+//! - `2021年11月8日(月)`
+//! - `令和3年11月8日` or `令和元年5月1日`
+//! - `R3.11.8` or `H31/4/30`
+//! - `令和3年11月8日(月) 午前0時32分`
+//!
+//! Full-width digits and punctuation are also accepted. Era years are validated against the
+//! era's boundaries eg. `平成31年5月1日` is an error as the Reiwa era started on 2019-05-01.
+use crate::errors::Error;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, Weekday};
+
+struct Era {
+    names: &'static [&'static str],
+    abbreviation: char,
+    start: (i32, u32, u32),
+}
+
+// newest first, each era ending the day before the following one in this list started.
+const ERAS: &[Era] = &[
+    Era {
+        names: &["令和", "㋿"],
+        abbreviation: 'R',
+        start: (2019, 5, 1),
+    },
+    Era {
+        names: &["平成", "㍻"],
+        abbreviation: 'H',
+        start: (1989, 1, 8),
+    },
+    Era {
+        names: &["昭和", "㍼"],
+        abbreviation: 'S',
+        start: (1926, 12, 25),
+    },
+    Era {
+        names: &["大正", "㍽"],
+        abbreviation: 'T',
+        start: (1912, 7, 30),
+    },
+    // retroactive from the lunar new year
+    Era {
+        names: &["明治", "㍾"],
+        abbreviation: 'M',
+        start: (1868, 1, 25),
+    },
+];
+
+const WEEKDAYS: [&str; 7] = ["月", "火", "水", "木", "金", "土", "日"];
+
+/// Attempts to parse the provided Japanese date into a `NaiveDate`.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `Date` format is provided, including era
+/// years outside of the era.
+pub fn parse_date(s: &str) -> Result<NaiveDate, Error> {
+    let s = normalize(s);
+    let (date, rest) = parse_date_prefix(&s).ok_or(Error::InvalidDate)?;
+    match parse_weekday(rest, date) {
+        Some("") => Ok(date),
+        _ => Err(Error::InvalidDate),
+    }
+}
+
+/// Attempts to parse the provided Japanese date, with an optional time, into a
+/// `DateTime`\<`FixedOffset`\>.
+///
+/// Times are assumed to be in UTC, and dates without a time are assumed to be midnight UTC.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `DateTime` format is provided, including era
+/// years outside of the era.
+pub fn parse(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = normalize(s);
+    let (date, rest) = parse_date_prefix(&s).ok_or(Error::InvalidDateTime)?;
+    let time = match parse_weekday(rest, date).ok_or(Error::InvalidDateTime)? {
+        "" => NaiveTime::MIN,
+        time => parse_time(time).ok_or(Error::InvalidDateTime)?,
+    };
+    Ok(date.and_time(time).and_utc().fixed_offset())
+}
+
+// replaces full-width digits and punctuation with their ASCII equivalents.
+fn normalize(s: &str) -> String {
+    s.trim()
+        .chars()
+        .map(|c| match c {
+            '０'..='９' | '（' | '）' | '：' | '．' | '／' | '－' => {
+                char::from_u32(u32::from(c) - 0xFEE0).unwrap_or(c)
+            }
+            '\u{3000}' => ' ',
+            c => c,
+        })
+        .collect()
+}
+
+// parses a leading date returning it along with the remainder.
+fn parse_date_prefix(s: &str) -> Option<(NaiveDate, &str)> {
+    for (idx, era) in ERAS.iter().enumerate() {
+        if let Some(rest) = era.names.iter().find_map(|name| s.strip_prefix(name)) {
+            let (year, rest) = match rest.strip_prefix("元") {
+                Some(rest) => (1, rest),
+                None => number(rest)?,
+            };
+            let (month, day, rest) = parse_month_day(rest.strip_prefix('年')?)?;
+            return Some((era_date(idx, year, month, day)?, rest));
+        }
+        let mut chars = s.chars();
+        if chars
+            .next()
+            .is_some_and(|c| c.eq_ignore_ascii_case(&era.abbreviation))
+        {
+            // eg. `R3.11.8`
+            let (year, rest) = number(chars.as_str())?;
+            let sep = rest
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '.' | '/' | '-'))?;
+            let (month, rest) = number(&rest[1..])?;
+            let (day, rest) = number(rest.strip_prefix(sep)?)?;
+            return Some((era_date(idx, year, month, day)?, rest));
+        }
+    }
+    let (year, rest) = number(s)?;
+    let (month, day, rest) = parse_month_day(rest.strip_prefix('年')?)?;
+    let date = NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)?;
+    Some((date, rest))
+}
+
+// `11月8日`
+fn parse_month_day(s: &str) -> Option<(u32, u32, &str)> {
+    let (month, rest) = number(s.trim_start())?;
+    let (day, rest) = number(rest.strip_prefix('月')?.trim_start())?;
+    Some((month, day, rest.strip_prefix('日')?))
+}
+
+// resolves the `year` of the era at `idx` in `ERAS`, checking the date falls within the era.
+fn era_date(idx: usize, year: u32, month: u32, day: u32) -> Option<NaiveDate> {
+    let (start_year, start_month, start_day) = ERAS[idx].start;
+    let start = NaiveDate::from_ymd_opt(start_year, start_month, start_day)?;
+    let year = start_year.checked_add(i32::try_from(year).ok()?.checked_sub(1)?)?;
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    let end = match idx.checked_sub(1) {
+        Some(next) => {
+            let (year, month, day) = ERAS[next].start;
+            NaiveDate::from_ymd_opt(year, month, day)?
+        }
+        None => NaiveDate::MAX,
+    };
+    (start..end).contains(&date).then_some(date)
+}
+
+// skips an optional weekday eg. `(月)`, `(月曜日)` or `月曜日`, checking it matches the `date`.
+fn parse_weekday(s: &str, date: NaiveDate) -> Option<&str> {
+    let rest = s.trim_start();
+    let (inner, rest) = match rest.strip_prefix('(') {
+        Some(rest) => rest.split_once(')')?,
+        None if rest.contains('曜') => {
+            rest.split_at(rest.find(char::is_whitespace).unwrap_or(rest.len()))
+        }
+        None => return Some(rest),
+    };
+    let name = inner.trim();
+    let name = name
+        .strip_suffix("曜日")
+        .or_else(|| name.strip_suffix('曜'))
+        .unwrap_or(name);
+    let weekday = WEEKDAYS.iter().position(|w| *w == name)?;
+    (Weekday::try_from(u8::try_from(weekday).ok()?).ok()? == date.weekday())
+        .then_some(rest.trim_start())
+}
+
+// `午前0時32分`, `15時5分45秒`, `3時半` or `午後3:05`
+fn parse_time(s: &str) -> Option<NaiveTime> {
+    let (pm, s) = match (s.strip_prefix("午前"), s.strip_prefix("午後")) {
+        (Some(s), _) => (Some(false), s),
+        (_, Some(s)) => (Some(true), s),
+        _ => (None, s),
+    };
+    let (hour, rest) = number(s.trim_start())?;
+    let (minute, second, rest) = if let Some(rest) = rest.strip_prefix(':') {
+        let (minute, rest) = number(rest)?;
+        match rest.strip_prefix(':') {
+            Some(rest) => {
+                let (second, rest) = number(rest)?;
+                (minute, second, rest)
+            }
+            None => (minute, 0, rest),
+        }
+    } else {
+        let rest = rest.strip_prefix('時')?;
+        if let Some(rest) = rest.strip_prefix('半') {
+            (30, 0, rest)
+        } else if let Some((minute, rest)) = number(rest) {
+            let rest = rest.strip_prefix('分')?;
+            match number(rest) {
+                Some((second, rest)) => (minute, second, rest.strip_prefix('秒')?),
+                None => (minute, 0, rest),
+            }
+        } else {
+            (0, 0, rest)
+        }
+    };
+    if !rest.trim().is_empty() {
+        return None;
+    }
+    let hour = match pm {
+        Some(pm) if hour <= 12 => hour % 12 + if pm { 12 } else { 0 },
+        Some(_) => return None,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, second)
+}
+
+// parses 1 to 4 leading ASCII digits, returning the value and the remainder.
+fn number(s: &str) -> Option<(u32, &str)> {
+    let len = s.bytes().take_while(u8::is_ascii_digit).count();
+    if len == 0 || len > 4 {
+        return None;
+    }
+    Some((s[..len].parse().ok()?, &s[len..]))
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("2021年11月8日", (2021, 11, 8)),
+            ("2021年11月8日(月)", (2021, 11, 8)),
+            ("２０２１年１１月８日（月曜日）", (2021, 11, 8)),
+            ("2021年11月8日 月曜日", (2021, 11, 8)),
+            ("令和3年11月8日", (2021, 11, 8)),
+            ("㋿3年11月8日", (2021, 11, 8)),
+            ("R3.11.8", (2021, 11, 8)),
+            ("r03/11/08", (2021, 11, 8)),
+            // era boundaries
+            ("令和元年5月1日", (2019, 5, 1)),
+            ("平成31年4月30日", (2019, 4, 30)),
+            ("H31.4.30", (2019, 4, 30)),
+            ("平成元年1月8日", (1989, 1, 8)),
+            ("昭和64年1月7日", (1989, 1, 7)),
+            ("昭和元年12月25日", (1926, 12, 25)),
+            ("大正15年12月24日", (1926, 12, 24)),
+            ("大正元年7月30日", (1912, 7, 30)),
+            ("明治45年7月29日", (1912, 7, 29)),
+        ] {
+            let (y, m, d) = *expected;
+            assert_eq!(
+                NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+                parse_date(input)?,
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn datetimes() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("令和3年11月8日(月) 午前0時32分", 1636331520),
+            ("2021年11月8日 0時32分45秒", 1636331565),
+            ("2021年11月8日 午後3時半", 1636385400),
+            ("R3.11.8 午後12時", 1636372800),
+            ("2021年11月8日(月) 00:32:45", 1636331565),
+            ("2021年11月8日", 1636329600),
+        ] {
+            assert_eq!(*expected, parse(input)?.timestamp(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "令和元年4月30日",
+            "平成31年5月1日",
+            "H31.5.1",
+            "昭和64年1月8日",
+            "大正15年12月25日",
+            "明治45年7月30日",
+            "令和0年5月1日",
+            "R3.11/8",
+            "2021年11月8日(火)",
+            "2021年13月8日",
+            "2021年11月8日 午後13時",
+            "2021年11月8日 25時",
+            "平成31年",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Kubernetes `klog` header parsing functions
+//!
+//! Supports the header of `klog` and `glog` lines eg.
+//! `I1108 00:32:45.426737   12345 file.go:12] message`, whose timestamps have no year or
+//! timezone.
+use crate::datetime::normalize_fraction;
+use crate::errors::Error;
+use crate::yearless::{YearPolicy, LEAP_YEAR};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Offset, Utc};
+
+/// The severity of a `klog` line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// `I`
+    Info,
+    /// `W`
+    Warning,
+    /// `E`
+    Error,
+    /// `F`
+    Fatal,
+}
+
+/// A parsed `klog` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header<'a> {
+    /// The severity eg. [`Severity::Info`] for `I1108`.
+    pub severity: Severity,
+    /// The header timestamp.
+    pub timestamp: DateTime<FixedOffset>,
+    /// The id of the thread that logged the line.
+    pub thread_id: u64,
+    /// The source file that logged the line eg. `file.go`.
+    pub file: &'a str,
+    /// The line number within the source file.
+    pub line: u32,
+    /// The remainder of the line following the header.
+    pub message: &'a str,
+}
+
+/// Attempts to parse the `klog` header of the provided line.
+///
+/// Timestamps have their year inferred using [`YearPolicy::Nearest`] relative to the `reference`
+/// and are assumed to be in UTC, see [`parse_with`] to change these.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `klog` header is provided.
+#[inline]
+pub fn parse(line: &str, reference: DateTime<FixedOffset>) -> Result<Header<'_>, Error> {
+    parse_with(line, reference, YearPolicy::Nearest, Utc.fix())
+}
+
+/// Attempts to parse the `klog` header of the provided line.
+///
+/// Timestamps have their year inferred from the `reference` according to the `policy` and are
+/// assumed to be in the provided `offset`.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `klog` header is provided.
+pub fn parse_with(
+    line: &str,
+    reference: DateTime<FixedOffset>,
+    policy: YearPolicy,
+    offset: FixedOffset,
+) -> Result<Header<'_>, Error> {
+    let severity = match line.as_bytes().first() {
+        Some(b'I') => Severity::Info,
+        Some(b'W') => Severity::Warning,
+        Some(b'E') => Severity::Error,
+        Some(b'F') => Severity::Fatal,
+        _ => return Err(Error::InvalidDateTime),
+    };
+    let (date, rest) = line[1..].split_once(' ').ok_or(Error::InvalidDateTime)?;
+    let (time, rest) = rest.split_once(' ').ok_or(Error::InvalidDateTime)?;
+    if date.len() != 4 || !date.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    let (month, day) = date.split_at(2);
+    let date = NaiveDate::from_ymd_opt(
+        LEAP_YEAR,
+        month.parse().map_err(|_| Error::InvalidDateTime)?,
+        day.parse().map_err(|_| Error::InvalidDateTime)?,
+    )
+    .ok_or(Error::InvalidDateTime)?;
+    // exactly `hh:mm:ss.uuuuuu`, chrono alone would also accept single digit fields
+    let b = time.as_bytes();
+    if b.len() < 8 || b[2] != b':' || b[5] != b':' {
+        return Err(Error::InvalidDateTime);
+    }
+    let time = NaiveTime::parse_from_str(&normalize_fraction(time), "%H:%M:%S%.f")
+        .map_err(|_| Error::InvalidDateTime)?;
+    let timestamp = crate::yearless::resolve(date.and_time(time), offset, reference, policy)?;
+
+    // the thread id is padded to a minimum width
+    let (thread_id, rest) = rest
+        .trim_start()
+        .split_once(' ')
+        .ok_or(Error::InvalidDateTime)?;
+    let (source, message) = rest.split_once(']').ok_or(Error::InvalidDateTime)?;
+    let (file, line) = source.rsplit_once(':').ok_or(Error::InvalidDateTime)?;
+    if file.is_empty() || !thread_id.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    Ok(Header {
+        severity,
+        timestamp,
+        thread_id: thread_id.parse().map_err(|_| Error::InvalidDateTime)?,
+        file,
+        line: line.parse().map_err(|_| Error::InvalidDateTime)?,
+        message: message.strip_prefix(' ').unwrap_or(message),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headers() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2021-11-10T12:00:00Z")?;
+        for (input, severity, expected, thread_id, file, line, message) in &[
+            (
+                "I1108 00:32:45.426737   12345 file.go:12] starting",
+                Severity::Info,
+                1636331565426737000,
+                12345,
+                "file.go",
+                12,
+                "starting",
+            ),
+            (
+                "W1108 00:32:45.426737 1 controller.go:301] retrying: timeout",
+                Severity::Warning,
+                1636331565426737000,
+                1,
+                "controller.go",
+                301,
+                "retrying: timeout",
+            ),
+            (
+                "E1108 00:32:45,426737 7 main.go:1]",
+                Severity::Error,
+                1636331565426737000,
+                7,
+                "main.go",
+                1,
+                "",
+            ),
+        ] {
+            let header = parse(input, reference)?;
+            assert_eq!(*severity, header.severity, "{input}");
+            assert_eq!(
+                *expected,
+                header.timestamp.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+            assert_eq!(*thread_id, header.thread_id, "{input}");
+            assert_eq!(*file, header.file, "{input}");
+            assert_eq!(*line, header.line, "{input}");
+            assert_eq!(*message, header.message, "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn year_and_offset() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2022-01-01T00:00:10Z")?;
+        let header = parse("F1231 23:59:59.000000 1 main.go:1] fatal", reference)?;
+        assert_eq!(
+            crate::datetime::parse("2021-12-31T23:59:59Z")?,
+            header.timestamp
+        );
+        let offset = FixedOffset::west_opt(7 * 3600).unwrap();
+        let header = parse_with(
+            "I1231 17:00:05.000000 1 main.go:1] msg",
+            reference,
+            YearPolicy::Past,
+            offset,
+        )?;
+        assert_eq!(
+            crate::datetime::parse("2022-01-01T00:00:05Z")?,
+            header.timestamp
+        );
+        assert_eq!(offset, *header.timestamp.offset());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2021-11-10T12:00:00Z")?;
+        for input in &[
+            "",
+            "X1108 00:32:45.426737 1 file.go:12] msg",
+            "I11080 00:32:45.426737 1 file.go:12] msg",
+            "I1308 00:32:45.426737 1 file.go:12] msg",
+            "I1108 25:32:45.426737 1 file.go:12] msg",
+            "I1108 00:32:45.426737 abc file.go:12] msg",
+            "I1108 00:32:45.426737 1 file.go] msg",
+            "I1108 00:32:45.426737 1 file.go:12 msg",
+            // the time is exactly `hh:mm:ss.uuuuuu`
+            "I1108 10am 1 a:1] x",
+            "I1108 noon 1 a:1] x",
+            "I1108 14h 1 a:1] x",
+            "I1108 0:32:45.426737 1 file.go:12] msg",
+            "I1108 00:32 1 file.go:12] msg",
+            "I1108 00:32:45Z 1 file.go:12] msg",
+        ] {
+            assert!(parse(input, reference).is_err(), "{input}");
+        }
+        Ok(())
+    }
+}
@@ -15,16 +15,45 @@
 //!
 //! Optional features:
 //!
+//! - `locale`: Parse French, German, Spanish, Italian, Portuguese, Dutch, Polish and Russian
+//!   month and weekday names via the `locale` module and `Parser::locale`.
 //! - [`serde`][]: Enable `deserialize_with` helper functions via serde.
+//! - `tz`: Resolve iCalendar `TZID`s in the `ical` module via [`chrono-tz`][].
 //!
 //! [`serde`]: https://github.com/serde-rs/serde
+//! [`chrono-tz`]: https://github.com/chronotope/chrono-tz
 //!
 
+pub mod asn1;
 pub mod date;
 pub mod datetime;
+pub mod email;
 pub mod errors;
+pub mod exif;
+pub mod format;
+pub mod http;
+pub mod ical;
+pub mod ids;
+pub mod japanese;
+pub mod klog;
+#[cfg(feature = "locale")]
+pub mod locale;
+pub mod parser;
+pub mod path;
+pub mod postgres;
+pub mod scan;
 #[cfg(feature = "serde")]
 pub mod serde;
+pub mod sql;
+pub mod syslog;
+pub mod time;
+pub mod toml;
+pub mod yaml;
+pub mod yearless;
 
 #[doc(inline)]
-pub use datetime::{parse, parse_utc};
+pub use datetime::{parse, parse_prefix, parse_utc};
+#[doc(inline)]
+pub use format::Format;
+#[doc(inline)]
+pub use parser::Parser;
@@ -0,0 +1,474 @@
+//! Localized `Date` & `DateTime` parsing functions
+//!
+//! Translates localized month and weekday names, including their genitive forms and
+//! abbreviations, to English before parsing eg. `8 novembre 2021`, `8. November 2021`,
+//! `8 de noviembre de 2021` or `8 listopada 2021`.
+//!
+//! ## Example
+//! ```rust
+//! use anydate::locale::{parse_any, parse_date, Locale};
+//! use chrono::NaiveDate;
+//!
+//! assert_eq!(
+//!     NaiveDate::from_ymd_opt(2021, 11, 8).unwrap(),
+//!     parse_date("lundi 8 novembre 2021", Locale::French).unwrap()
+//! );
+//! assert_eq!(Locale::Polish, parse_any("8 listopada 2021 r.").unwrap().1);
+//! ```
+use crate::errors::Error;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Weekday};
+
+/// A language whose month and weekday names are recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Locale {
+    /// French eg. `8 novembre 2021`.
+    French,
+    /// German eg. `8. November 2021`.
+    German,
+    /// Spanish eg. `8 de noviembre de 2021`.
+    Spanish,
+    /// Italian eg. `lunedì 8 novembre 2021`.
+    Italian,
+    /// Portuguese eg. `8 de novembro de 2021`.
+    Portuguese,
+    /// Dutch eg. `maandag 8 november 2021`.
+    Dutch,
+    /// Polish eg. `8 listopada 2021`.
+    Polish,
+    /// Russian eg. `8 ноября 2021`.
+    Russian,
+}
+
+impl Locale {
+    /// All supported locales, in the order they're tried by [`parse_any`].
+    pub const ALL: &'static [Locale] = &[
+        Locale::French,
+        Locale::German,
+        Locale::Spanish,
+        Locale::Italian,
+        Locale::Portuguese,
+        Locale::Dutch,
+        Locale::Polish,
+        Locale::Russian,
+    ];
+
+    // lowercase month names from January, including genitive forms and abbreviations.
+    #[allow(clippy::too_many_lines)]
+    const fn months(self) -> &'static [&'static [&'static str]; 12] {
+        match self {
+            Locale::French => &[
+                &["janvier", "janv", "jan"],
+                &["février", "fevrier", "févr", "fevr", "fév", "fev"],
+                &["mars"],
+                &["avril", "avr"],
+                &["mai"],
+                &["juin"],
+                &["juillet", "juil"],
+                &["août", "aout"],
+                &["septembre", "sept"],
+                &["octobre", "oct"],
+                &["novembre", "nov"],
+                &["décembre", "decembre", "déc", "dec"],
+            ],
+            Locale::German => &[
+                &["januar", "jänner", "jan", "jän"],
+                &["februar", "feb", "febr"],
+                &["märz", "maerz", "mär", "mrz"],
+                &["april", "apr"],
+                &["mai"],
+                &["juni", "jun"],
+                &["juli", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["dezember", "dez"],
+            ],
+            Locale::Spanish => &[
+                &["enero", "ene"],
+                &["febrero", "feb"],
+                &["marzo", "mar"],
+                &["abril", "abr"],
+                &["mayo", "may"],
+                &["junio", "jun"],
+                &["julio", "jul"],
+                &["agosto", "ago"],
+                &["septiembre", "setiembre", "sep", "sept", "set"],
+                &["octubre", "oct"],
+                &["noviembre", "nov"],
+                &["diciembre", "dic"],
+            ],
+            Locale::Italian => &[
+                &["gennaio", "gen"],
+                &["febbraio", "feb"],
+                &["marzo", "mar"],
+                &["aprile", "apr"],
+                &["maggio", "mag"],
+                &["giugno", "giu"],
+                &["luglio", "lug"],
+                &["agosto", "ago"],
+                &["settembre", "set"],
+                &["ottobre", "ott"],
+                &["novembre", "nov"],
+                &["dicembre", "dic"],
+            ],
+            Locale::Portuguese => &[
+                &["janeiro", "jan"],
+                &["fevereiro", "fev"],
+                &["março", "marco", "mar"],
+                &["abril", "abr"],
+                &["maio", "mai"],
+                &["junho", "jun"],
+                &["julho", "jul"],
+                &["agosto", "ago"],
+                &["setembro", "set"],
+                &["outubro", "out"],
+                &["novembro", "nov"],
+                &["dezembro", "dez"],
+            ],
+            Locale::Dutch => &[
+                &["januari", "jan"],
+                &["februari", "feb"],
+                &["maart", "mrt", "maa"],
+                &["april", "apr"],
+                &["mei"],
+                &["juni", "jun"],
+                &["juli", "jul"],
+                &["augustus", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["december", "dec"],
+            ],
+            Locale::Polish => &[
+                &["styczeń", "stycznia", "sty"],
+                &["luty", "lutego", "lut"],
+                &["marzec", "marca", "mar"],
+                &["kwiecień", "kwietnia", "kwi"],
+                &["maj", "maja"],
+                &["czerwiec", "czerwca", "cze"],
+                &["lipiec", "lipca", "lip"],
+                &["sierpień", "sierpnia", "sie"],
+                &["wrzesień", "września", "wrz"],
+                &["październik", "października", "paź", "paz"],
+                &["listopad", "listopada", "lis"],
+                &["grudzień", "grudnia", "gru"],
+            ],
+            Locale::Russian => &[
+                &["январь", "января", "янв"],
+                &["февраль", "февраля", "февр", "фев"],
+                &["март", "марта", "мар"],
+                &["апрель", "апреля", "апр"],
+                &["май", "мая"],
+                &["июнь", "июня", "июн"],
+                &["июль", "июля", "июл"],
+                &["август", "августа", "авг"],
+                &["сентябрь", "сентября", "сент", "сен"],
+                &["октябрь", "октября", "окт"],
+                &["ноябрь", "ноября", "нояб", "ноя"],
+                &["декабрь", "декабря", "дек"],
+            ],
+        }
+    }
+
+    // lowercase weekday names from Monday, including abbreviations.
+    const fn weekdays(self) -> &'static [&'static [&'static str]; 7] {
+        match self {
+            Locale::French => &[
+                &["lundi", "lun"],
+                &["mardi", "mar"],
+                &["mercredi", "mer"],
+                &["jeudi", "jeu"],
+                &["vendredi", "ven"],
+                &["samedi", "sam"],
+                &["dimanche", "dim"],
+            ],
+            Locale::German => &[
+                &["montag", "mo"],
+                &["dienstag", "di"],
+                &["mittwoch", "mi"],
+                &["donnerstag", "do"],
+                &["freitag", "fr"],
+                &["samstag", "sonnabend", "sa"],
+                &["sonntag", "so"],
+            ],
+            // `mar` is left to March
+            Locale::Spanish => &[
+                &["lunes", "lun"],
+                &["martes"],
+                &["miércoles", "miercoles", "mié", "mie"],
+                &["jueves", "jue"],
+                &["viernes", "vie"],
+                &["sábado", "sabado", "sáb", "sab"],
+                &["domingo", "dom"],
+            ],
+            Locale::Italian => &[
+                &["lunedì", "lunedi", "lun"],
+                &["martedì", "martedi"],
+                &["mercoledì", "mercoledi", "mer"],
+                &["giovedì", "giovedi", "gio"],
+                &["venerdì", "venerdi", "ven"],
+                &["sabato", "sab"],
+                &["domenica", "dom"],
+            ],
+            Locale::Portuguese => &[
+                &["segunda-feira", "segunda", "seg"],
+                &["terça-feira", "terca-feira", "terça", "terca", "ter"],
+                &["quarta-feira", "quarta", "qua"],
+                &["quinta-feira", "quinta", "qui"],
+                &["sexta-feira", "sexta", "sex"],
+                &["sábado", "sabado", "sáb", "sab"],
+                &["domingo", "dom"],
+            ],
+            Locale::Dutch => &[
+                &["maandag", "ma"],
+                &["dinsdag", "di"],
+                &["woensdag", "wo"],
+                &["donderdag", "do"],
+                &["vrijdag", "vr"],
+                &["zaterdag", "za"],
+                &["zondag", "zo"],
+            ],
+            Locale::Polish => &[
+                &["poniedziałek", "pon"],
+                &["wtorek", "wt"],
+                &["środa", "śr"],
+                &["czwartek", "czw"],
+                &["piątek", "pt"],
+                &["sobota", "sob"],
+                &["niedziela", "niedz", "ndz"],
+            ],
+            Locale::Russian => &[
+                &["понедельник", "пн"],
+                &["вторник", "вт"],
+                &["среда", "ср"],
+                &["четверг", "чт"],
+                &["пятница", "пт"],
+                &["суббота", "сб"],
+                &["воскресенье", "вс"],
+            ],
+        }
+    }
+
+    // lowercase words that are dropped eg. the `de` in `8 de noviembre de 2021`.
+    const fn fillers(self) -> &'static [&'static str] {
+        match self {
+            Locale::French => &["le", "er", "à"],
+            Locale::German => &["den", "am", "um", "uhr"],
+            Locale::Spanish => &["de", "del", "a", "las"],
+            Locale::Italian => &["il", "alle"],
+            Locale::Portuguese => &["de", "às"],
+            Locale::Dutch => &["om"],
+            Locale::Polish => &["r", "roku", "o"],
+            Locale::Russian => &["г", "года", "в"],
+        }
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Attempts to parse the provided string, using the `locale`'s month and weekday names, into a
+/// `DateTime`\<`FixedOffset`\>.
+///
+/// Dates without a time are assumed to be midnight UTC, as with [`crate::datetime::parse`].
+///
+/// # Errors
+/// Will return `Err` when no localized month name is found, or an invalid or unsupported
+/// `DateTime` format is provided.
+pub fn parse(s: &str, locale: Locale) -> Result<DateTime<FixedOffset>, Error> {
+    let (translated, weekday) = translate(s, locale).ok_or(Error::InvalidDateTime)?;
+    let dt = crate::datetime::parse(&translated)?;
+    check_weekday(dt.date_naive(), weekday).ok_or(Error::InvalidDateTime)?;
+    Ok(dt)
+}
+
+/// Attempts to parse the provided string, using the `locale`'s month and weekday names, into a
+/// `NaiveDate`.
+///
+/// # Errors
+/// Will return `Err` when no localized month name is found, or an invalid or unsupported `Date`
+/// format is provided.
+pub fn parse_date(s: &str, locale: Locale) -> Result<NaiveDate, Error> {
+    let (translated, weekday) = translate(s, locale).ok_or(Error::InvalidDate)?;
+    let date = crate::date::parse(&translated)?;
+    check_weekday(date, weekday).ok_or(Error::InvalidDate)
+}
+
+/// Attempts to parse the provided string, detecting which of [`Locale::ALL`] its month and
+/// weekday names are in, returning the `DateTime`\<`FixedOffset`\> along with the `Locale`.
+///
+/// # Errors
+/// Will return `Err` when no localized month name is found, or an invalid or unsupported
+/// `DateTime` format is provided.
+pub fn parse_any(s: &str) -> Result<(DateTime<FixedOffset>, Locale), Error> {
+    Locale::ALL
+        .iter()
+        .find_map(|locale| parse(s, *locale).ok().map(|dt| (dt, *locale)))
+        .ok_or(Error::InvalidDateTime)
+}
+
+// `Option::is_none_or` would raise the minimum supported Rust version to 1.82
+#[allow(clippy::unnecessary_map_or)]
+fn check_weekday(date: NaiveDate, weekday: Option<Weekday>) -> Option<NaiveDate> {
+    weekday
+        .map_or(true, |weekday| date.weekday() == weekday)
+        .then_some(date)
+}
+
+// Replaces the `locale`'s month names with English ones and drops filler words along with a
+// leading weekday, which is returned to be checked. Returns `None` when no month is found.
+fn translate(s: &str, locale: Locale) -> Option<(String, Option<Weekday>)> {
+    let mut out = String::with_capacity(s.len());
+    let mut weekday = None;
+    let mut found = false;
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_alphabetic() {
+            let mut end = start + c.len_utf8();
+            while let Some((idx, c)) = chars.peek().copied() {
+                // hyphenated words eg. `segunda-feira`
+                let hyphenated =
+                    c == '-' && s[idx + 1..].starts_with(char::is_alphabetic) && end > start;
+                if !c.is_alphabetic() && !hyphenated {
+                    break;
+                }
+                end = idx + c.len_utf8();
+                chars.next();
+            }
+            let word = s[start..end].to_lowercase();
+            let month = locale
+                .months()
+                .iter()
+                .position(|names| names.contains(&word.as_str()));
+            let day = locale
+                .weekdays()
+                .iter()
+                .position(|names| names.contains(&word.as_str()));
+            if let Some(month) = month {
+                found = true;
+                out.push_str(MONTHS[month]);
+            } else if let Some(day) = day {
+                // only a leading weekday is supported
+                if !out.trim().is_empty() || weekday.is_some() {
+                    return None;
+                }
+                weekday = Some(Weekday::try_from(u8::try_from(day).ok()?).ok()?);
+                while chars.next_if(|(_, c)| matches!(c, '.' | ',')).is_some() {}
+            } else if locale.fillers().contains(&word.as_str()) {
+                // keep a separator in place of `1er` in `1er novembre`
+                out.push(' ');
+            } else {
+                out.push_str(&s[start..end]);
+                continue;
+            }
+            // abbreviations eg. `nov.`
+            chars.next_if(|(_, c)| *c == '.');
+        } else if matches!(c, '.' | ',')
+            && s[..start].ends_with(|c: char| c.is_ascii_digit() || c == ' ')
+            && s[start + 1..].starts_with(char::is_whitespace)
+        {
+            // ordinal days eg. `8. November` and separators eg. `2021, 00:32`
+            out.push(' ');
+        } else {
+            out.push(c);
+        }
+    }
+    found.then(|| {
+        (
+            out.split_whitespace().collect::<Vec<_>>().join(" "),
+            weekday,
+        )
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, locale) in &[
+            ("8 novembre 2021", Locale::French),
+            ("lundi 8 novembre 2021", Locale::French),
+            ("Lun. 8 nov. 2021", Locale::French),
+            ("8. November 2021", Locale::German),
+            ("Montag, den 8. November 2021", Locale::German),
+            ("8 de noviembre de 2021", Locale::Spanish),
+            ("lunes, 8 de noviembre de 2021", Locale::Spanish),
+            ("8 novembre 2021", Locale::Italian),
+            ("lunedì 8 novembre 2021", Locale::Italian),
+            ("8 de novembro de 2021", Locale::Portuguese),
+            ("segunda-feira, 8 de novembro de 2021", Locale::Portuguese),
+            ("8 november 2021", Locale::Dutch),
+            ("maandag 8 november 2021", Locale::Dutch),
+            ("8 listopada 2021", Locale::Polish),
+            ("poniedziałek, 8 listopada 2021 r.", Locale::Polish),
+            ("8 ноября 2021 г.", Locale::Russian),
+            ("понедельник, 8 ноября 2021", Locale::Russian),
+            ("8 ноя 2021", Locale::Russian),
+        ] {
+            assert_eq!(
+                NaiveDate::from_ymd_opt(2021, 11, 8).unwrap(),
+                parse_date(input, *locale)?,
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn datetimes() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, locale, expected) in &[
+            ("8 novembre 2021 00:32:45", Locale::French, 1636331565),
+            ("8. März 2021 00:32:45", Locale::German, 1615163565),
+            ("1er février 2021", Locale::French, 1612137600),
+            ("8 ноября 2021", Locale::Russian, 1636329600),
+        ] {
+            assert_eq!(*expected, parse(input, *locale)?.timestamp(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn detect() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("8 novembre 2021", Locale::French),
+            ("8. Dezember 2021", Locale::German),
+            ("8 de noviembre de 2021", Locale::Spanish),
+            ("8 listopada 2021", Locale::Polish),
+            ("8 ноября 2021", Locale::Russian),
+        ] {
+            assert_eq!(*expected, parse_any(input)?.1, "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for (input, locale) in &[
+            ("", Locale::French),
+            ("8 November 2021", Locale::French),
+            ("mardi 8 novembre 2021", Locale::French),
+            ("8 novembre 2021 lundi", Locale::French),
+            ("31 listopada 2021", Locale::Polish),
+        ] {
+            assert!(parse_date(input, *locale).is_err(), "{input}");
+        }
+        assert!(parse_any("8 Novembre").is_err());
+    }
+}
@@ -0,0 +1,275 @@
+//! Configurable `DateTime` parser
+//!
+//! ## Example
+//! ```rust
+//! use anydate::Parser;
+//! use chrono::NaiveDate;
+//!
+//! let parser = Parser::new().sentinel_date(NaiveDate::from_ymd_opt(9999, 12, 31).unwrap());
+//! assert_eq!(None, parser.parse("0000-00-00 00:00:00").unwrap());
+//! assert_eq!(None, parser.parse("N/A").unwrap());
+//! assert_eq!(None, parser.parse("9999-12-31").unwrap());
+//! assert!(parser.parse("2021-11-08").unwrap().is_some());
+//! assert!(parser.parse("invalid junk").is_err());
+//! ```
+use crate::errors::Error;
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use std::borrow::Cow;
+
+// `null`-like values recognized by default, matched ignoring case and surrounding whitespace.
+const DEFAULT_SENTINELS: &[Cow<'static, str>] = &[
+    Cow::Borrowed(""),
+    Cow::Borrowed("-"),
+    Cow::Borrowed("N/A"),
+    Cow::Borrowed("null"),
+];
+
+/// Parses `DateTime`s, recognizing sentinel values that stand in for a missing value.
+///
+/// By default `MySQL` zero dates eg. `0000-00-00 00:00:00` or `2021-00-00`, empty strings, `-`,
+/// `N/A` and `null` are sentinels. Dates such as `1900-01-01` or `9999-12-31` are only sentinels
+/// when added using [`Parser::sentinel_date`].
+#[derive(Debug, Clone)]
+pub struct Parser {
+    sentinels: Cow<'static, [Cow<'static, str>]>,
+    sentinel_dates: Vec<NaiveDate>,
+    zero_dates: bool,
+    ids: bool,
+    #[cfg(feature = "locale")]
+    locales: Vec<crate::locale::Locale>,
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self {
+            sentinels: Cow::Borrowed(DEFAULT_SENTINELS),
+            sentinel_dates: Vec::new(),
+            zero_dates: true,
+            ids: false,
+            #[cfg(feature = "locale")]
+            locales: Vec::new(),
+        }
+    }
+}
+
+impl Parser {
+    /// Creates a new `Parser` with the default sentinels.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sentinel value, matched ignoring case and surrounding whitespace.
+    #[must_use]
+    pub fn sentinel(mut self, sentinel: impl Into<Cow<'static, str>>) -> Self {
+        self.sentinels.to_mut().push(sentinel.into());
+        self
+    }
+
+    /// Adds a sentinel date, any `DateTime` on this date in its own offset is a sentinel.
+    #[must_use]
+    pub fn sentinel_date(mut self, date: NaiveDate) -> Self {
+        self.sentinel_dates.push(date);
+        self
+    }
+
+    /// Whether `MySQL` zero dates, with a zero year, month or day, are sentinels.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub const fn zero_dates(mut self, zero_dates: bool) -> Self {
+        self.zero_dates = zero_dates;
+        self
+    }
+
+    /// Whether to extract the creation time of identifiers such as UUIDs, ULIDs and `ObjectId`s,
+    /// see [`crate::ids::parse`].
+    ///
+    /// Defaults to `false`.
+    #[must_use]
+    pub const fn ids(mut self, ids: bool) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Adds a locale whose month and weekday names are recognized when the string isn't
+    /// otherwise supported, tried in the order they're added.
+    ///
+    /// See [`crate::locale::Locale::ALL`] to detect any supported locale.
+    #[cfg(feature = "locale")]
+    #[must_use]
+    pub fn locale(mut self, locale: crate::locale::Locale) -> Self {
+        self.locales.push(locale);
+        self
+    }
+
+    /// Removes all sentinels, including the defaults.
+    #[must_use]
+    pub fn clear_sentinels(mut self) -> Self {
+        self.sentinels = Cow::Borrowed(&[]);
+        self.sentinel_dates.clear();
+        self.zero_dates = false;
+        self
+    }
+
+    /// Attempts to parse the provided string into a `DateTime`\<`FixedOffset`\>, returning `None`
+    /// when it's a sentinel.
+    ///
+    /// # Errors
+    /// Will return `Err` when an invalid or unsupported `DateTime` format is provided.
+    pub fn parse(&self, s: &str) -> Result<Option<DateTime<FixedOffset>>, Error> {
+        let trimmed = s.trim();
+        if self
+            .sentinels
+            .iter()
+            .any(|sentinel| sentinel.eq_ignore_ascii_case(trimmed))
+            || (self.zero_dates && is_zero_date(trimmed))
+        {
+            return Ok(None);
+        }
+        let dt = match crate::datetime::parse(s) {
+            Ok(dt) => dt,
+            Err(e) => self.parse_fallback(trimmed).ok_or(e)?,
+        };
+        if self.sentinel_dates.contains(&dt.date_naive()) {
+            return Ok(None);
+        }
+        Ok(Some(dt))
+    }
+
+    // the optional formats tried when `datetime::parse` fails.
+    fn parse_fallback(&self, s: &str) -> Option<DateTime<FixedOffset>> {
+        if self.ids {
+            if let Ok(dt) = crate::ids::parse(s) {
+                return Some(dt.fixed_offset());
+            }
+        }
+        #[cfg(feature = "locale")]
+        if let Some(dt) = self
+            .locales
+            .iter()
+            .find_map(|locale| crate::locale::parse(s, *locale).ok())
+        {
+            return Some(dt);
+        }
+        None
+    }
+}
+
+// MySQL zero dates eg. `0000-00-00`, `2021-00-00` or `2021-11-00 00:32:45`, which have a zero
+// year, month or day and are optionally followed by a time.
+fn is_zero_date(s: &str) -> bool {
+    let b = s.as_bytes();
+    if b.len() < 10
+        || b[4] != b'-'
+        || b[7] != b'-'
+        || ![0..4, 5..7, 8..10]
+            .into_iter()
+            .all(|range| b[range].iter().all(u8::is_ascii_digit))
+    {
+        return false;
+    }
+    let zero = &b[..4] == b"0000" || &b[5..7] == b"00" || &b[8..10] == b"00";
+    let time = &b[10..];
+    zero && (time.is_empty()
+        || (matches!(time[0], b' ' | b'T')
+            && time[1..]
+                .iter()
+                .all(|c| c.is_ascii_digit() || matches!(c, b':' | b'.'))))
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sentinels() -> Result<(), Box<dyn std::error::Error>> {
+        let parser = Parser::new();
+        for input in &[
+            "0000-00-00 00:00:00",
+            "0000-00-00",
+            "2021-00-00",
+            "2021-11-00 00:32:45.000",
+            "0000-11-08",
+            "",
+            "  ",
+            "-",
+            "n/a",
+            "NULL",
+        ] {
+            assert_eq!(None, parser.parse(input)?, "{input}");
+        }
+        assert_eq!(
+            1636331565,
+            parser.parse("2021-11-08 00:32:45")?.unwrap().timestamp()
+        );
+        assert!(parser.parse("1900-01-01")?.is_some());
+        assert!(parser.parse("invalid junk").is_err());
+        assert!(parser.parse("2021-00-00 junk").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn configured() -> Result<(), Box<dyn std::error::Error>> {
+        let parser = Parser::new()
+            .sentinel("unknown")
+            .sentinel_date(NaiveDate::from_ymd_opt(1900, 1, 1).unwrap())
+            .sentinel_date(NaiveDate::from_ymd_opt(9999, 12, 31).unwrap())
+            .zero_dates(false);
+        for input in &[
+            "Unknown",
+            "N/A",
+            "1900-01-01",
+            "9999-12-31 23:59:59",
+            "9999-12-31T23:59:59.999-07:00",
+        ] {
+            assert_eq!(None, parser.parse(input)?, "{input}");
+        }
+        assert!(parser.parse("0000-00-00").is_err());
+
+        let parser = parser.clear_sentinels();
+        assert!(parser.parse("N/A").is_err());
+        assert!(parser.parse("1900-01-01")?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn ids() -> Result<(), Box<dyn std::error::Error>> {
+        let input = "017cfcf6-3172-7cc3-98c4-dc0c0c07398f";
+        assert!(Parser::new().parse(input).is_err());
+        let parser = Parser::new().ids(true);
+        assert_eq!(
+            1636331565426,
+            parser.parse(input)?.unwrap().timestamp_millis()
+        );
+        assert_eq!(
+            1636331565,
+            parser.parse("2021-11-08 00:32:45")?.unwrap().timestamp()
+        );
+        assert!(parser.parse("invalid junk").is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "locale")]
+    #[test]
+    fn locales() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::locale::Locale;
+
+        assert!(Parser::new().parse("8 novembre 2021").is_err());
+        let parser = Parser::new().locale(Locale::German).locale(Locale::French);
+        for input in &[
+            "8 novembre 2021",
+            "8. November 2021",
+            "Montag, 8. Nov. 2021",
+        ] {
+            assert_eq!(
+                1636329600,
+                parser.parse(input)?.unwrap().timestamp(),
+                "{input}"
+            );
+        }
+        assert!(parser.parse("8 listopada 2021").is_err());
+        Ok(())
+    }
+}
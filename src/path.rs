@@ -0,0 +1,204 @@
+//! Path and filename `Date` & `DateTime` extraction functions
+//!
+//! Filenames and URL paths often embed a timestamp among other text, and replace the `:` in its
+//! time, which isn't allowed in all filesystems, with `-`, `_` or `.` eg.
+//! `IMG_20211108_003245.jpg`, `backup-2021-11-08T00-32-45.tar.gz`, `/2021/11/08/slug` or
+//! `log.2021-11-08.gz`.
+//!
+//! ## Example
+//! ```rust
+//! use anydate::path::from_path;
+//! use anydate::scan::Value;
+//!
+//! let Value::DateTime(dt) = from_path("IMG_20211108_003245.jpg").unwrap() else {
+//!     panic!("expected a DateTime");
+//! };
+//! assert_eq!(1636331565, dt.timestamp());
+//! ```
+use crate::errors::Error;
+use crate::scan::Value;
+use chrono::{NaiveDate, NaiveTime};
+
+/// Attempts to extract the most specific `Date` or `DateTime` embedded in the provided path or
+/// filename, preferring the first when several are equally specific.
+///
+/// Dates have a four digit year followed by the month and day, separated by one of `-`, `_`, `.`
+/// or `/`, or not separated at all. They may be followed by a time, whose components are
+/// separated by one of `:`, `-`, `_` or `.`, or not separated at all. The time is separated from
+/// the date by a `T`, or when it includes the seconds by one of `_`, `-` or a space or not
+/// separated at all. `DateTime`s are assumed to be in UTC.
+///
+/// # Errors
+/// Will return `Err` when the path contains no `Date` or `DateTime`.
+pub fn from_path(s: &str) -> Result<Value, Error> {
+    let b = s.as_bytes();
+    (0..b.len())
+        .filter(|idx| *idx == 0 || !b[idx - 1].is_ascii_digit())
+        .filter_map(|idx| parse_at(b, idx))
+        // `max_by_key` returns the last of equally specific matches
+        .rev()
+        .max_by_key(|(_, specificity)| *specificity)
+        .map(|(value, _)| value)
+        .ok_or(Error::InvalidDateTime)
+}
+
+// parses a date, and optionally a time, starting at `idx` returning it along with how many of its
+// components were present.
+fn parse_at(b: &[u8], idx: usize) -> Option<(Value, usize)> {
+    let (year, idx) = digits(b, idx, 4)?;
+    let sep = byte(b, idx, b"-_./");
+    let (month, idx) = digits(b, sep.map_or(idx, |(_, i)| i), 2)?;
+    let idx = match sep {
+        Some((sep, _)) => byte(b, idx, &[sep])?.1,
+        None => idx,
+    };
+    let (day, idx) = digits(b, idx, 2)?;
+    let date = NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)?;
+
+    if let Some((time, specificity)) = parse_time(b, idx, sep.is_none()) {
+        let dt = date.and_time(time).and_utc().fixed_offset();
+        return Some((Value::DateTime(dt), specificity));
+    }
+    // a compact date directly followed by more digits is part of a larger number
+    (!b.get(idx).is_some_and(u8::is_ascii_digit)).then_some((Value::Date(date), 3))
+}
+
+// parses a time following a date at `idx`, which may directly follow a compact date when
+// `compact`.
+fn parse_time(b: &[u8], idx: usize, compact: bool) -> Option<(NaiveTime, usize)> {
+    // a `.` is more likely to precede a version or build number eg. `release-2021-11-08.1234.tar`
+    // as is `-` or `_` followed by four digits eg. `backup-2021-11-08-1234.tar`, so only a `T`
+    // may be followed by a time without seconds
+    let (idx, needs_seconds) = match byte(b, idx, b"Tt_- ") {
+        // macOS screenshots eg. `Screenshot 2021-11-08 at 00.32.45.png`
+        Some(_) if b[idx..].starts_with(b" at ") => (idx + 4, true),
+        Some((b'T' | b't', i)) => (i, false),
+        Some((_, i)) => (i, true),
+        None if compact => (idx, true),
+        None => return None,
+    };
+    let (hour, idx) = digits(b, idx, 2)?;
+    let sep = byte(b, idx, b":-_.");
+    let (minute, idx) = digits(b, sep.map_or(idx, |(_, i)| i), 2)?;
+    let (second, mut idx) = match sep {
+        Some((sep, _)) => byte(b, idx, &[sep]).and_then(|(_, i)| digits(b, i, 2)),
+        None => digits(b, idx, 2),
+    }
+    .map_or((None, idx), |(second, idx)| (Some(second), idx));
+    if needs_seconds && second.is_none() {
+        return None;
+    }
+
+    let mut nanos = 0;
+    let mut specificity = 5;
+    // compact times may be directly followed by their milliseconds eg. `003245426`
+    let fraction = match sep {
+        None if b.get(idx).is_some_and(u8::is_ascii_digit) => Some((b'.', idx)),
+        _ => byte(b, idx, b".,").filter(|(c, _)| sep.map(|(sep, _)| sep) != Some(*c)),
+    };
+    if let Some((_, start)) = second.and(fraction) {
+        let len = b[start..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if len > 0 {
+            idx = start + len;
+            specificity = 7;
+            // truncate to nanoseconds
+            nanos = (start..start + 9).fold(0, |nanos, i| {
+                nanos * 10
+                    + b.get(i)
+                        .filter(|_| i < idx)
+                        .map_or(0, |d| u32::from(d - b'0'))
+            });
+        }
+    } else if second.is_some() {
+        specificity = 6;
+    }
+    if let Some((_, i)) = byte(b, idx, b"Zz") {
+        idx = i;
+    }
+    if b.get(idx).is_some_and(u8::is_ascii_digit) {
+        return None;
+    }
+    NaiveTime::from_hms_nano_opt(hour, minute, second.unwrap_or_default(), nanos)
+        .map(|time| (time, specificity))
+}
+
+// parses exactly `n` ASCII digits starting at `idx`, returning the value and the index after.
+fn digits(b: &[u8], idx: usize, n: usize) -> Option<(u32, usize)> {
+    let value = b.get(idx..idx + n)?.iter().try_fold(0, |value, d| {
+        d.is_ascii_digit().then(|| value * 10 + u32::from(d - b'0'))
+    })?;
+    Some((value, idx + n))
+}
+
+fn byte(b: &[u8], idx: usize, expected: &[u8]) -> Option<(u8, usize)> {
+    b.get(idx)
+        .filter(|c| expected.contains(c))
+        .map(|c| (*c, idx + 1))
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetimes() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("IMG_20211108_003245.jpg", 1636331565000000000),
+            ("backup-2021-11-08T00-32-45.tar.gz", 1636331565000000000),
+            ("backup-2021-11-08-00-32-45.tar", 1636331565000000000),
+            ("Screenshot 2021-11-08 at 00.32.45.png", 1636331565000000000),
+            ("PXL_20211108_003245426.jpg", 1636331565426000000),
+            ("dump_20211108003245.sql", 1636331565000000000),
+            ("trace-2021-11-08_00-32-45.426Z.json", 1636331565426000000),
+            (
+                "logs/2021-11-08/app-2021-11-08T00:32.log",
+                1636331520000000000,
+            ),
+        ] {
+            let Value::DateTime(dt) = from_path(input)? else {
+                panic!("{input}");
+            };
+            assert_eq!(*expected, dt.timestamp_nanos_opt().unwrap(), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dates() -> Result<(), Box<dyn std::error::Error>> {
+        for input in &[
+            "/2021/11/08/slug",
+            "https://example.com/blog/2021/11/08/slug.html",
+            "log.2021-11-08.gz",
+            "report_20211108.pdf",
+            "2021_11_08",
+            // a build number rather than a time
+            "release-2021-11-08.1234.tar",
+            "backup-2021-11-08-1234.tar",
+            "backup_20211108_1234.tar",
+        ] {
+            assert_eq!(
+                Value::Date(NaiveDate::from_ymd_opt(2021, 11, 8).unwrap()),
+                from_path(input)?,
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "slug",
+            "IMG_1234.jpg",
+            "2021-11.log",
+            "2021-13-08.log",
+            "2021-11/08.log",
+            "120211108.log",
+            "202111080032.log",
+        ] {
+            assert!(from_path(input).is_err(), "{input}");
+        }
+    }
+}
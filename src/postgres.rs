@@ -0,0 +1,289 @@
+//! `PostgreSQL` output parsing functions
+//!
+//! Understands the text output of `psql` and `COPY` for the `timestamptz`, `timestamp` and `date`
+//! types in all four `DateStyle` output formats:
+//!
+//! - `ISO` eg. `2021-11-08 00:32:45.426+05:30:15`
+//! - `SQL` eg. `11/08/2021 00:32:45.426 PST`
+//! - `Postgres` eg. `Mon Nov 08 00:32:45.426 2021 PST`
+//! - `German` eg. `08.11.2021 00:32:45.426 PST`
+//!
+//! as well as `BC` suffixes eg. `0044-03-15 BC` and the special values `infinity`, `-infinity`
+//! and `epoch`.
+//!
+//! Values without an offset are assumed to be in UTC and dates are midnight UTC.
+//!
+//! ## Example
+//! ```rust
+//! use anydate::postgres::{parse, Value};
+//!
+//! assert_eq!(Value::Infinity, parse("infinity").unwrap());
+//! assert!(matches!(parse("2021-11-08 00:32:45+00").unwrap(), Value::DateTime(_)));
+//! ```
+use crate::datetime::{parse_numeric_offset, parse_offset};
+use crate::errors::Error;
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+};
+
+/// A parsed `PostgreSQL` timestamp or date value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    /// A finite timestamp or date.
+    DateTime(DateTime<FixedOffset>),
+    /// `infinity`, later than all other values.
+    Infinity,
+    /// `-infinity`, earlier than all other values.
+    NegativeInfinity,
+}
+
+/// The order of the day and month in the `SQL`, `Postgres` and `German` `DateStyle`s, set by the
+/// second component of `DateStyle` eg. `SQL, DMY`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateOrder {
+    /// Month before day eg. `11/08/2021`, the `PostgreSQL` default.
+    #[default]
+    Mdy,
+    /// Day before month eg. `08/11/2021`.
+    Dmy,
+}
+
+/// Attempts to parse the provided `PostgreSQL` output, see [`parse_with`] for `DateStyle`s using
+/// [`DateOrder::Dmy`].
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported value is provided.
+#[inline]
+pub fn parse(s: &str) -> Result<Value, Error> {
+    parse_with(s, DateOrder::default())
+}
+
+/// Attempts to parse the provided `PostgreSQL` output, using the `order` for ambiguous day and
+/// month positions.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported value is provided.
+pub fn parse_with(s: &str, order: DateOrder) -> Result<Value, Error> {
+    if s.eq_ignore_ascii_case("infinity") || s.eq_ignore_ascii_case("+infinity") {
+        return Ok(Value::Infinity);
+    }
+    if s.eq_ignore_ascii_case("-infinity") {
+        return Ok(Value::NegativeInfinity);
+    }
+    if s.eq_ignore_ascii_case("epoch") {
+        return Ok(Value::DateTime(DateTime::UNIX_EPOCH.fixed_offset()));
+    }
+
+    let (s, bc) = s.strip_suffix(" BC").map_or((s, false), |s| (s, true));
+    let (mut ndt, offset) = parse_timestamp(s, order)?;
+    if bc {
+        // 1 BC is year 0
+        ndt = ndt
+            .with_year(1 - ndt.year())
+            .ok_or(Error::InvalidDateTime)?;
+    }
+    offset
+        .from_local_datetime(&ndt)
+        .single()
+        .map(Value::DateTime)
+        .ok_or(Error::InvalidDateTime)
+}
+
+fn parse_timestamp(s: &str, order: DateOrder) -> Result<(NaiveDateTime, FixedOffset), Error> {
+    if let Some(ndt) = parse_naive(s, order) {
+        return Ok((ndt, Utc.fix()));
+    }
+    // `ISO` appends the offset directly to the time, the other styles a zone abbreviation
+    let iso = s
+        .rfind(['+', '-'])
+        .filter(|idx| *idx > 10)
+        .map(|idx| s.split_at(idx));
+    iso.into_iter()
+        .chain(s.rsplit_once(' '))
+        .find_map(|(naive, tz)| Some((parse_naive(naive, order)?, parse_zone(tz).ok()?)))
+        .ok_or(Error::InvalidDateTime)
+}
+
+fn parse_naive(s: &str, order: DateOrder) -> Option<NaiveDateTime> {
+    let (datetime_formats, date_formats): (&[&str], &[&str]) = match order {
+        DateOrder::Mdy => (
+            &[
+                "%Y-%m-%d %H:%M:%S%.f",
+                "%m/%d/%Y %H:%M:%S%.f",
+                "%a %b %d %H:%M:%S%.f %Y",
+                "%d.%m.%Y %H:%M:%S%.f",
+            ],
+            &["%Y-%m-%d", "%m/%d/%Y", "%m-%d-%Y", "%d.%m.%Y"],
+        ),
+        DateOrder::Dmy => (
+            &[
+                "%Y-%m-%d %H:%M:%S%.f",
+                "%d/%m/%Y %H:%M:%S%.f",
+                "%a %d %b %H:%M:%S%.f %Y",
+                "%d.%m.%Y %H:%M:%S%.f",
+            ],
+            &["%Y-%m-%d", "%d/%m/%Y", "%d-%m-%Y", "%d.%m.%Y"],
+        ),
+    };
+    datetime_formats
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+        .or_else(|| {
+            date_formats
+                .iter()
+                .find_map(|fmt| NaiveDate::parse_from_str(s, fmt).ok())
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+}
+
+fn parse_zone(tz: &str) -> Result<FixedOffset, Error> {
+    parse_iso_offset(tz)
+        .or_else(|_| parse_numeric_offset(tz))
+        .or_else(|_| parse_offset(tz))
+}
+
+// `ISO` offsets eg. `+00`, `+05:30` or `+05:30:15` for local mean time.
+fn parse_iso_offset(tz: &str) -> Result<FixedOffset, Error> {
+    let (sign, rest) = match tz.as_bytes().first() {
+        Some(b'+') => (1, &tz[1..]),
+        Some(b'-') => (-1, &tz[1..]),
+        _ => return Err(Error::InvalidDateTime),
+    };
+    let mut secs = 0;
+    for (idx, part) in rest.split(':').enumerate() {
+        if idx > 2 || part.len() != 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidDateTime);
+        }
+        let value: i32 = part.parse().map_err(|_| Error::InvalidDateTime)?;
+        if idx > 0 && value > 59 {
+            return Err(Error::InvalidDateTime);
+        }
+        secs += value * [3600, 60, 1][idx];
+    }
+    FixedOffset::east_opt(sign * secs).ok_or(Error::InvalidDateTime)
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    fn timestamp(value: Value) -> i64 {
+        match value {
+            Value::DateTime(dt) => dt.timestamp_nanos_opt().unwrap(),
+            value => panic!("unexpected {value:?}"),
+        }
+    }
+
+    #[test]
+    fn special_values() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(Value::Infinity, parse("infinity")?);
+        assert_eq!(Value::Infinity, parse("+Infinity")?);
+        assert_eq!(Value::NegativeInfinity, parse("-infinity")?);
+        assert_eq!(0, timestamp(parse("epoch")?));
+        Ok(())
+    }
+
+    #[test]
+    fn iso() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("2021-11-08 00:32:45.426+00", 1636331565426000000),
+            ("2021-11-08 06:02:45.42+05:30", 1636331565420000000),
+            ("2021-11-08 06:03:00.42+05:30:15", 1636331565420000000),
+            ("2021-11-07 16:32:45-08", 1636331565000000000),
+            ("2021-11-08 00:32:45", 1636331565000000000),
+            ("2021-11-08", 1636329600000000000),
+        ] {
+            assert_eq!(*expected, timestamp(parse(input)?), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn bc() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("0044-03-15 BC", (-43, 3, 15)),
+            ("0044-03-15 10:00:00+00 BC", (-43, 3, 15)),
+            ("0001-01-01 BC", (0, 1, 1)),
+            ("03/15/0044 BC", (-43, 3, 15)),
+        ] {
+            let Value::DateTime(dt) = parse(input)? else {
+                panic!("{input}");
+            };
+            assert_eq!(*expected, (dt.year(), dt.month(), dt.day()), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn date_styles() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, order, expected) in &[
+            // SQL
+            (
+                "11/08/2021 00:32:45.426 UTC",
+                DateOrder::Mdy,
+                1636331565426000000,
+            ),
+            (
+                "11/07/2021 16:32:45 PST",
+                DateOrder::Mdy,
+                1636331565000000000,
+            ),
+            (
+                "08/11/2021 00:32:45.426 UTC",
+                DateOrder::Dmy,
+                1636331565426000000,
+            ),
+            ("11/08/2021", DateOrder::Mdy, 1636329600000000000),
+            // Postgres
+            (
+                "Mon Nov 08 00:32:45.426 2021 UTC",
+                DateOrder::Mdy,
+                1636331565426000000,
+            ),
+            (
+                "Sun Nov 07 16:32:45 2021 PST",
+                DateOrder::Mdy,
+                1636331565000000000,
+            ),
+            (
+                "Mon 08 Nov 00:32:45 2021 CET",
+                DateOrder::Dmy,
+                1636327965000000000,
+            ),
+            (
+                "Mon Nov 08 06:02:45 2021 +0530",
+                DateOrder::Mdy,
+                1636331565000000000,
+            ),
+            ("11-08-2021", DateOrder::Mdy, 1636329600000000000),
+            ("08-11-2021", DateOrder::Dmy, 1636329600000000000),
+            // German
+            ("08.11.2021 00:32:45", DateOrder::Mdy, 1636331565000000000),
+            (
+                "08.11.2021 01:32:45.426 CET",
+                DateOrder::Dmy,
+                1636331565426000000,
+            ),
+            ("08.11.2021", DateOrder::Mdy, 1636329600000000000),
+        ] {
+            assert_eq!(*expected, timestamp(parse_with(input, *order)?), "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "infinite",
+            "2021-11-08 00:32:45+5",
+            "2021-11-08 00:32:45+05:30:15:00",
+            "2021-11-08 00:32:45 XYZ",
+            "13/13/2021",
+            "2021-11-08 AD",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
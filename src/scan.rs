@@ -0,0 +1,268 @@
+//! Functions for finding `Date`s and `DateTime`s embedded in free text
+//!
+//! ## Example
+//! ```rust
+//! use anydate::scan::{scan, Value};
+//!
+//! let text = "Meeting moved to Friday, March 13th, 2020 from 2020-03-12 17:00:00.";
+//! for m in scan(text) {
+//!     println!("{:?} {:?} {:?}", &text[m.span.clone()], m.value, m.format);
+//! }
+//! ```
+use crate::format::Format;
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use std::ops::Range;
+
+// most words a single match can span eg. `Mon Nov 08 2021 00:32:45 GMT+0000 (Pacific Standard Time)`
+const MAX_WORDS: usize = 10;
+
+/// A `Date` or `DateTime` found within text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Byte range of the match within the scanned text.
+    pub span: Range<usize>,
+    /// The parsed value.
+    pub value: Value,
+    /// The format the match was recognized as.
+    pub format: Format,
+}
+
+/// A parsed `Date` or `DateTime` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    /// Recognized by [`crate::date::parse`].
+    Date(NaiveDate),
+    /// Recognized by [`crate::datetime::parse`].
+    DateTime(DateTime<FixedOffset>),
+}
+
+/// Scans text for `Date`s and `DateTime`s, see [`scan`] for the defaults.
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner {
+    longest: bool,
+    skip_numbers: bool,
+}
+
+impl Default for Scanner {
+    fn default() -> Self {
+        Self {
+            longest: true,
+            skip_numbers: true,
+        }
+    }
+}
+
+impl Scanner {
+    /// Creates a new `Scanner` with the default options.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to prefer the longest or the shortest match starting at a given word, eg.
+    /// `2021-11-08 00:32:45` rather than `2021-11-08`.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub const fn longest(mut self, longest: bool) -> Self {
+        self.longest = longest;
+        self
+    }
+
+    /// Whether to skip bare numbers that would otherwise be recognized as unix timestamps,
+    /// including pairs of numbers recognized as Mercurial's raw format.
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub const fn skip_numbers(mut self, skip_numbers: bool) -> Self {
+        self.skip_numbers = skip_numbers;
+        self
+    }
+
+    /// Returns an iterator over all non-overlapping matches found in the provided text.
+    pub fn scan<'a>(&self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        Matches {
+            scanner: *self,
+            text,
+            pos: 0,
+        }
+    }
+
+    fn parse(self, s: &str) -> Option<(Value, Format)> {
+        crate::date::parse_with_format(s)
+            .map(|(date, format)| (Value::Date(date), format))
+            .or_else(|_| {
+                crate::datetime::parse_with_format(s)
+                    .map(|(dt, format)| (Value::DateTime(dt), format))
+            })
+            .ok()
+            .filter(|(_, format)| {
+                !(self.skip_numbers && matches!(format, Format::UnixTimestamp | Format::Mercurial))
+            })
+    }
+}
+
+/// Returns an iterator over all non-overlapping `Date`s and `DateTime`s found in the provided
+/// text, preferring the longest match and skipping bare numbers.
+///
+/// See [`Scanner`] to change these options.
+pub fn scan(text: &str) -> impl Iterator<Item = Match> + '_ {
+    Scanner::default().scan(text)
+}
+
+struct Matches<'a> {
+    scanner: Scanner,
+    text: &'a str,
+    pos: usize,
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(start) = next_word(self.text, self.pos) {
+            let mut ends = word_ends(self.text, start);
+            if self.scanner.longest {
+                ends.reverse();
+            }
+            let found = ends.into_iter().find_map(|end| {
+                let (value, format) = self.scanner.parse(&self.text[start..end])?;
+                Some(Match {
+                    span: start..end,
+                    value,
+                    format,
+                })
+            });
+            match found {
+                Some(m) => {
+                    self.pos = m.span.end;
+                    return Some(m);
+                }
+                None => self.pos = word_end(self.text, start),
+            }
+        }
+        None
+    }
+}
+
+// returns the start of the next word at or after `pos`, skipping any opening punctuation.
+fn next_word(text: &str, pos: usize) -> Option<usize> {
+    text[pos..]
+        .char_indices()
+        .find(|(_, c)| !c.is_whitespace() && !is_opening(*c))
+        .map(|(idx, _)| pos + idx)
+}
+
+fn word_end(text: &str, start: usize) -> usize {
+    text[start..]
+        .find(char::is_whitespace)
+        .map_or(text.len(), |idx| start + idx)
+}
+
+// returns the candidate match ends for the words starting at `start`, shortest first.
+fn word_ends(text: &str, start: usize) -> Vec<usize> {
+    let mut ends = Vec::with_capacity(MAX_WORDS * 2);
+    let mut end = start;
+    for _ in 0..MAX_WORDS {
+        end = word_end(text, end);
+        let trimmed = start + text[start..end].trim_end_matches(is_closing).len();
+        if trimmed > start && trimmed < end {
+            ends.push(trimmed);
+        }
+        ends.push(end);
+        match text[end..].find(|c: char| !c.is_whitespace()) {
+            Some(idx) => end += idx,
+            None => break,
+        }
+    }
+    ends
+}
+
+const fn is_opening(c: char) -> bool {
+    matches!(c, '(' | '[' | '{' | '<' | '"' | '\'')
+}
+
+const fn is_closing(c: char) -> bool {
+    matches!(
+        c,
+        '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '>' | '"' | '\''
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    fn spans(text: &str, scanner: Scanner) -> Vec<(&str, Format)> {
+        scanner
+            .scan(text)
+            .map(|m| (&text[m.span], m.format))
+            .collect()
+    }
+
+    #[test]
+    fn free_text() {
+        let text =
+            "Meeting moved to Friday, March 13th, 2020 at 3pm PST (was 2020-03-12 17:00:00).";
+        assert_eq!(
+            vec![
+                (
+                    "Friday, March 13th, 2020",
+                    Format::Strftime("%A %B %eth %Y")
+                ),
+                (
+                    "2020-03-12 17:00:00",
+                    Format::Strftime("%Y-%m-%d %H:%M:%S%.f")
+                ),
+            ],
+            spans(text, Scanner::new())
+        );
+    }
+
+    #[test]
+    fn values() {
+        let text = "shipped 2021-11-08T00:32:45Z, delivered on 7 oct 1970.";
+        let matches: Vec<_> = scan(text).collect();
+        assert_eq!(2, matches.len());
+        assert_eq!(8..28, matches[0].span);
+        assert_eq!(Format::Rfc3339, matches[0].format);
+        assert!(matches!(matches[0].value, Value::DateTime(dt) if dt.timestamp() == 1636331565));
+        assert_eq!(
+            Value::Date(NaiveDate::from_ymd_opt(1970, 10, 7).unwrap()),
+            matches[1].value
+        );
+    }
+
+    #[test]
+    fn options() {
+        let text = "order 1636331565 placed 2021-11-08 00:32:45";
+        assert_eq!(
+            vec!["2021-11-08 00:32:45"],
+            spans(text, Scanner::new())
+                .into_iter()
+                .map(|(s, _)| s)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["1636331565", "2021-11-08"],
+            spans(text, Scanner::new().longest(false).skip_numbers(false))
+                .into_iter()
+                .map(|(s, _)| s)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn no_matches() {
+        for text in &[
+            "",
+            "   ",
+            "nothing to see here",
+            "call me at 555 1234",
+            " 00)'+9日",
+        ] {
+            assert_eq!(0, scan(text).count(), "{text}");
+        }
+    }
+}
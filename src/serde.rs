@@ -20,6 +20,47 @@ impl<'de> de::Visitor<'de> for AnydateVisitor {
     }
 }
 
+struct AnydateOptionVisitor;
+
+impl<'de> de::Visitor<'de> for AnydateOptionVisitor {
+    type Value = Option<DateTime<FixedOffset>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .write_str("a formatted date and time string, a unix timestamp, a sentinel or null")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, d: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        d.deserialize_str(self)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        crate::parser::Parser::default()
+            .parse(value)
+            .map_err(E::custom)
+    }
+}
+
 pub mod deserialize {
     //! deserialize helper functions
     //!
@@ -40,7 +81,7 @@ pub mod deserialize {
     //! println!("{:?}", dt);
     //!
     //! ```
-    use super::{de, AnydateVisitor, DateTime, FixedOffset, Utc};
+    use super::{de, AnydateOptionVisitor, AnydateVisitor, DateTime, FixedOffset, Utc};
 
     /// deserializes to a [`DateTime<FixedOffset>`]
     ///
@@ -53,7 +94,8 @@ pub mod deserialize {
         d.deserialize_str(AnydateVisitor)
     }
 
-    /// deserializes to a [`Option<DateTime<FixedOffset>>`]
+    /// deserializes to a [`Option<DateTime<FixedOffset>>`], `null` and the default
+    /// [`Parser`](crate::Parser) sentinels such as `0000-00-00` or `N/A` deserialize to `None`
     ///
     /// # Errors
     /// Will return `Err` when an invalid or unsupported `DateTime` format is provided.
@@ -61,7 +103,7 @@ pub mod deserialize {
     where
         D: de::Deserializer<'de>,
     {
-        Ok(d.deserialize_str(AnydateVisitor).ok())
+        d.deserialize_option(AnydateOptionVisitor)
     }
 
     /// deserializes to a [`DateTime<Utc>`]
@@ -75,7 +117,8 @@ pub mod deserialize {
         Ok(d.deserialize_str(AnydateVisitor)?.with_timezone(&Utc))
     }
 
-    /// deserializes to a [`Option<DateTime<Utc>>`]
+    /// deserializes to a [`Option<DateTime<Utc>>`], `null` and the default
+    /// [`Parser`](crate::Parser) sentinels such as `0000-00-00` or `N/A` deserialize to `None`
     ///
     /// # Errors
     /// Will return `Err` when an invalid or unsupported `DateTime` format is provided.
@@ -83,8 +126,8 @@ pub mod deserialize {
     where
         D: de::Deserializer<'de>,
     {
-        Ok(d.deserialize_str(AnydateVisitor)
-            .map_or_else(|_| None, |dt| Some(dt.with_timezone(&Utc))))
+        Ok(d.deserialize_option(AnydateOptionVisitor)?
+            .map(|dt| dt.with_timezone(&Utc)))
     }
 
     #[cfg(test)]
@@ -108,6 +151,18 @@ pub mod deserialize {
                     json!({"dt":"Mon, 15 Nov 2021 02:49:37 +0000"}),
                     1636944577000000000,
                 ),
+                (
+                    json!({"dt":"/Date(1636944446061+0100)/"}),
+                    1636944446061000000,
+                ),
+                (
+                    serde_json::from_str(r#"{"dt":"\/Date(1636944446061)\/"}"#)?,
+                    1636944446061000000,
+                ),
+                (
+                    json!({"dt":"2021-11-15T02:42:26.0610000"}),
+                    1636944146061000000,
+                ),
             ] {
                 let s: Test = serde_json::from_value(input)?;
                 assert_eq!(s.dt.timestamp_nanos_opt().unwrap(), expected);
@@ -133,7 +188,9 @@ pub mod deserialize {
                     Some(1636944577000000000),
                 ),
                 (json!({ "dt": null }), None),
-                (json!({ "dt": "invalid junk" }), None),
+                (json!({ "dt": "0000-00-00 00:00:00" }), None),
+                (json!({ "dt": "2021-00-00" }), None),
+                (json!({ "dt": "N/A" }), None),
             ] {
                 let s: Test = serde_json::from_value(input)?;
                 match expected {
@@ -145,6 +202,7 @@ pub mod deserialize {
                     }
                 };
             }
+            assert!(serde_json::from_value::<Test>(json!({ "dt": "invalid junk" })).is_err());
             Ok(())
         }
 
@@ -187,7 +245,9 @@ pub mod deserialize {
                     Some(1636944577000000000),
                 ),
                 (json!({ "dt": null }), None),
-                (json!({ "dt": "invalid junk" }), None),
+                (json!({ "dt": "0000-00-00 00:00:00" }), None),
+                (json!({ "dt": "2021-00-00" }), None),
+                (json!({ "dt": "N/A" }), None),
             ] {
                 let s: Test = serde_json::from_value(input)?;
                 match expected {
@@ -199,6 +259,7 @@ pub mod deserialize {
                     }
                 };
             }
+            assert!(serde_json::from_value::<Test>(json!({ "dt": "invalid junk" })).is_err());
             Ok(())
         }
     }
@@ -0,0 +1,183 @@
+//! SQL literal and database text output parsing functions
+//!
+//! Recognizes the vendor specific text forms found in database exports:
+//!
+//! - ANSI SQL literals eg. `TIMESTAMP '2021-11-08 00:32:45'` or `DATE '2021-11-08'`
+//! - SQL Server's default `CONVERT` styles eg. `Nov  8 2021 12:32AM`
+//! - Oracle's default `DD-MON-RR` formats eg. `08-NOV-21` or `08-NOV-21 12.32.45.000000 AM`
+//! - SAS `DATETIME` and `DATE` formats eg. `08NOV2021:00:32:45` or `08NOV2021`
+//!
+//! As with [`crate::datetime::parse`], values without an offset are assumed to be in UTC and
+//! dates are midnight UTC.
+use crate::datetime::{parse_numeric_offset, parse_offset};
+use crate::errors::Error;
+use chrono::{
+    DateTime, FixedOffset, Month, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+};
+
+/// Attempts to parse the provided SQL literal or database text output into a
+/// `DateTime`\<`FixedOffset`\>.
+///
+/// Oracle's two digit `RR` years are interpreted as 2000 to 2049 for `00` to `49` and 1950 to
+/// 1999 otherwise.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported format is provided.
+pub fn parse(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    parse_literal(s)
+        .or_else(|_| parse_sql_server(s).map(|dt| dt.and_utc().fixed_offset()))
+        .or_else(|_| parse_oracle(s))
+        .or_else(|_| parse_sas(s).map(|dt| dt.and_utc().fixed_offset()))
+}
+
+// ANSI literals eg. `TIMESTAMP '2021-11-08 00:32:45'` or `DATE '2021-11-08'`
+fn parse_literal(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (keyword, value) = s
+        .strip_suffix('\'')
+        .and_then(|s| s.split_once('\''))
+        .ok_or(Error::InvalidDateTime)?;
+    let keyword = keyword
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_uppercase();
+    match keyword.as_str() {
+        "DATE" => Ok(crate::date::parse(value)
+            .map_err(|_| Error::InvalidDateTime)?
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+            .fixed_offset()),
+        "TIMESTAMP" | "TIMESTAMP WITH TIME ZONE" | "TIMESTAMP WITHOUT TIME ZONE" => {
+            crate::datetime::parse(value)
+        }
+        _ => Err(Error::InvalidDateTime),
+    }
+}
+
+// SQL Server's default `CONVERT` styles 0 and 9 eg. `Nov  8 2021 12:32AM` or
+// `Nov  8 2021 12:32:45:426AM`
+fn parse_sql_server(s: &str) -> Result<NaiveDateTime, Error> {
+    const PARSE_FORMATS: &[&str] = &["%b %e %Y %I:%M%p", "%b %e %Y %I:%M:%S:%3f%p"];
+    PARSE_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+        .ok_or(Error::InvalidDateTime)
+}
+
+// Oracle's default `NLS_DATE_FORMAT` and `NLS_TIMESTAMP_FORMAT` eg. `08-NOV-21` or
+// `08-NOV-21 12.32.45.000000 AM`, optionally followed by the zone of a `TIMESTAMP WITH TIME ZONE`.
+fn parse_oracle(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (date, time) = s.split_once(' ').unwrap_or((s, ""));
+    let mut parts = date.splitn(3, '-');
+    let (Some(day), Some(month), Some(year)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(Error::InvalidDateTime);
+    };
+    if day.len() != 2 || month.len() != 3 || !matches!(year.len(), 2 | 4) {
+        return Err(Error::InvalidDateTime);
+    }
+    if !day.bytes().chain(year.bytes()).all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    let day = day.parse().map_err(|_| Error::InvalidDateTime)?;
+    let month = month
+        .parse::<Month>()
+        .map_err(|_| Error::InvalidDateTime)?
+        .number_from_month();
+    let year = match (
+        year.len(),
+        year.parse().map_err(|_| Error::InvalidDateTime)?,
+    ) {
+        (2, y) if y < 50 => y + 2000,
+        (2, y) => y + 1900,
+        (_, y) => y,
+    };
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or(Error::InvalidDateTime)?;
+    if time.is_empty() {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc().fixed_offset());
+    }
+
+    // any zone follows the day period
+    let (time, offset) = match time.match_indices(' ').nth(1) {
+        Some((idx, _)) => {
+            let tz = &time[idx + 1..];
+            let offset = parse_numeric_offset(tz).or_else(|_| parse_offset(tz))?;
+            (&time[..idx], offset)
+        }
+        None => (time, Utc.fix()),
+    };
+    let time =
+        NaiveTime::parse_from_str(time, "%I.%M.%S%.f %p").map_err(|_| Error::InvalidDateTime)?;
+    offset
+        .from_local_datetime(&date.and_time(time))
+        .single()
+        .ok_or(Error::InvalidDateTime)
+}
+
+// SAS `DATETIME` and `DATE9` formats eg. `08NOV2021:00:32:45` or `08NOV2021`
+fn parse_sas(s: &str) -> Result<NaiveDateTime, Error> {
+    NaiveDateTime::parse_from_str(s, "%d%b%Y:%H:%M:%S%.f")
+        .or_else(|_| {
+            NaiveDate::parse_from_str(s, "%d%b%Y").map(|date| date.and_time(NaiveTime::MIN))
+        })
+        .map_err(|_| Error::InvalidDateTime)
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sql() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            // ansi literals
+            ("TIMESTAMP '2021-11-08 00:32:45'", 1636331565000000000),
+            ("timestamp '2021-11-08 00:32:45.426'", 1636331565426000000),
+            (
+                "TIMESTAMP WITH TIME ZONE '2021-11-08 01:32:45+01:00'",
+                1636331565000000000,
+            ),
+            ("DATE '2021-11-08'", 1636329600000000000),
+            // sql server
+            ("Nov  8 2021 12:32AM", 1636331520000000000),
+            ("Nov 18 2021  3:32PM", 1637249520000000000),
+            ("Nov  8 2021 12:32:45:426AM", 1636331565426000000),
+            // oracle
+            ("08-NOV-21", 1636329600000000000),
+            ("08-NOV-99", 942019200000000000),
+            ("08-Nov-2021", 1636329600000000000),
+            ("08-NOV-21 12.32.45.000000 AM", 1636331565000000000),
+            ("08-NOV-21 12.32.45.426000 PM", 1636374765426000000),
+            ("08-NOV-21 01.32.45.000000 AM +01:00", 1636331565000000000),
+            ("08-NOV-21 04.32.45.000000 PM PST", 1636417965000000000),
+            // sas
+            ("08NOV2021:00:32:45", 1636331565000000000),
+            ("08NOV2021:00:32:45.426", 1636331565426000000),
+            ("08NOV2021", 1636329600000000000),
+        ] {
+            assert_eq!(
+                *expected,
+                parse(input)?.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "TIMESTAMP 2021-11-08 00:32:45",
+            "INTERVAL '1' DAY",
+            "DATE '2021-11-08 junk'",
+            "8-NOV-21",
+            "08-NOV-21 12:32:45 AM",
+            "08-NOV-21 12.32.45.000000 AM XYZ",
+            "08-SMA-21",
+            "Nov  8 2021",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
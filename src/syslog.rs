@@ -0,0 +1,258 @@
+//! Syslog header timestamp parsing functions
+//!
+//! Supports both RFC 3164 headers eg. `<34>Oct 11 22:14:15 mymachine su: ...`, whose timestamps
+//! have no year or timezone, and RFC 5424 headers eg.
+//! `<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog ...`.
+use crate::errors::Error;
+use crate::yearless::YearPolicy;
+use chrono::{DateTime, FixedOffset, Offset, Utc};
+
+/// A parsed syslog header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header<'a> {
+    /// The PRI part eg. `34` for `<34>`, if present.
+    pub priority: Option<u8>,
+    /// The RFC 5424 version, `None` for RFC 3164 headers.
+    pub version: Option<u16>,
+    /// The header timestamp, `None` when the RFC 5424 nil value `-` is used.
+    pub timestamp: Option<DateTime<FixedOffset>>,
+    /// The remainder of the line following the timestamp eg. `mymachine su: ...`.
+    pub message: &'a str,
+}
+
+/// Attempts to parse the syslog header of the provided line.
+///
+/// RFC 3164 timestamps have their year inferred using [`YearPolicy::Nearest`] relative to the
+/// `reference` and are assumed to be in UTC, see [`parse_with`] to change these.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported syslog header is provided.
+#[inline]
+pub fn parse(line: &str, reference: DateTime<FixedOffset>) -> Result<Header<'_>, Error> {
+    parse_with(line, reference, YearPolicy::Nearest, Utc.fix())
+}
+
+/// Attempts to parse the syslog header of the provided line.
+///
+/// RFC 3164 timestamps have their year inferred from the `reference` according to the `policy`
+/// and are assumed to be in the provided `offset`.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported syslog header is provided.
+pub fn parse_with(
+    line: &str,
+    reference: DateTime<FixedOffset>,
+    policy: YearPolicy,
+    offset: FixedOffset,
+) -> Result<Header<'_>, Error> {
+    let (priority, rest) = parse_priority(line)?;
+    if let Some((version, rest)) = parse_version(rest) {
+        let (timestamp, message) = rest.split_once(' ').unwrap_or((rest, ""));
+        let timestamp = match timestamp {
+            "-" => None,
+            _ => Some(DateTime::parse_from_rfc3339(timestamp).map_err(|_| Error::InvalidDateTime)?),
+        };
+        return Ok(Header {
+            priority,
+            version: Some(version),
+            timestamp,
+            message,
+        });
+    }
+    let (timestamp, message) = parse_rfc3164_timestamp(rest, reference, policy, offset)?;
+    Ok(Header {
+        priority,
+        version: None,
+        timestamp: Some(timestamp),
+        message: message.trim_start(),
+    })
+}
+
+// optional PRI part eg. `<34>`
+fn parse_priority(line: &str) -> Result<(Option<u8>, &str), Error> {
+    let Some(rest) = line.strip_prefix('<') else {
+        return Ok((None, line));
+    };
+    let (pri, rest) = rest.split_once('>').ok_or(Error::InvalidDateTime)?;
+    if pri.is_empty() || pri.len() > 3 || !pri.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    match pri.parse::<u8>() {
+        Ok(pri) if pri <= 191 => Ok((Some(pri), rest)),
+        _ => Err(Error::InvalidDateTime),
+    }
+}
+
+// RFC 5424 VERSION eg. `1`, directly following the PRI part, a non-zero digit followed by up to
+// two digits.
+fn parse_version(s: &str) -> Option<(u16, &str)> {
+    let (version, rest) = s.split_once(' ')?;
+    if !(1..=3).contains(&version.len())
+        || version.starts_with('0')
+        || !version.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((version.parse().ok()?, rest))
+}
+
+// RFC 3164 TIMESTAMP eg. `Oct 11 22:14:15` or `Nov  8 00:32:45` with the day space padded.
+fn parse_rfc3164_timestamp(
+    s: &str,
+    reference: DateTime<FixedOffset>,
+    policy: YearPolicy,
+    offset: FixedOffset,
+) -> Result<(DateTime<FixedOffset>, &str), Error> {
+    // some devices mark timestamps that are not synchronized eg. `*Mar  1 18:46:11.123:`
+    let s = s.trim_start_matches(['*', '.']);
+    let end = (0..3).try_fold(0, |idx, _| {
+        let start = idx + s[idx..].find(|c: char| !c.is_whitespace())?;
+        Some(
+            s[start..]
+                .find(char::is_whitespace)
+                .map_or(s.len(), |i| start + i),
+        )
+    });
+    if let Some(end) = end {
+        if let Ok((ndt, tz)) = crate::yearless::parse_naive(s[..end].trim_end_matches(':')) {
+            let dt = crate::yearless::resolve(ndt, tz.unwrap_or(offset), reference, policy)?;
+            return Ok((dt, &s[end..]));
+        }
+    }
+    // some senders use a complete timestamp instead eg. rsyslog's high precision format, but a
+    // leading bare number is a malformed RFC 5424 VERSION rather than a unix timestamp
+    let token = s.split_whitespace().next().unwrap_or_default();
+    if token.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidDateTime);
+    }
+    crate::datetime::parse_prefix(s)
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc3164() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2003-10-20T00:00:00Z")?;
+        for (input, priority, expected, message) in &[
+            (
+                "<34>Oct 11 22:14:15 mymachine su: 'su root' failed",
+                Some(34),
+                1065910455000000000,
+                "mymachine su: 'su root' failed",
+            ),
+            (
+                "<13>Oct  1 09:05:03 host app: msg",
+                Some(13),
+                1064999103000000000,
+                "host app: msg",
+            ),
+            (
+                "Oct 11 22:14:15 mymachine su: msg",
+                None,
+                1065910455000000000,
+                "mymachine su: msg",
+            ),
+            (
+                "<189>*Oct 11 22:14:15.123: %SYS-5-CONFIG_I",
+                Some(189),
+                1065910455123000000,
+                "%SYS-5-CONFIG_I",
+            ),
+            (
+                "<34>2003-10-11T22:14:15.003-07:00 host msg",
+                Some(34),
+                1065935655003000000,
+                "host msg",
+            ),
+        ] {
+            let header = parse(input, reference)?;
+            assert_eq!(*priority, header.priority);
+            assert_eq!(None, header.version);
+            assert_eq!(
+                *expected,
+                header.timestamp.unwrap().timestamp_nanos_opt().unwrap()
+            );
+            assert_eq!(*message, header.message);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rfc3164_year_and_offset() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2022-01-01T00:00:10Z")?;
+        let offset = FixedOffset::west_opt(7 * 3600).unwrap();
+        let header = parse("<34>Dec 31 17:00:05 host msg", reference)?;
+        assert_eq!(
+            crate::datetime::parse("2021-12-31T17:00:05Z")?,
+            header.timestamp.unwrap()
+        );
+        let header = parse_with(
+            "<34>Dec 31 17:00:05 host msg",
+            reference,
+            YearPolicy::Past,
+            offset,
+        )?;
+        assert_eq!(
+            crate::datetime::parse("2022-01-01T00:00:05Z")?,
+            header.timestamp.unwrap()
+        );
+        assert_eq!(offset, *header.timestamp.unwrap().offset());
+        Ok(())
+    }
+
+    #[test]
+    fn rfc5424() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2003-10-20T00:00:00Z")?;
+        for (input, expected, message) in &[
+            (
+                "<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 - msg",
+                Some(1065910455003000000),
+                "mymachine.example.com evntslog - ID47 - msg",
+            ),
+            (
+                "<165>1 2003-08-24T05:14:15.000003-07:00 192.0.2.1 myproc 8710 - - msg",
+                Some(1061727255000003000),
+                "192.0.2.1 myproc 8710 - - msg",
+            ),
+            ("<165>1 - host app - - - msg", None, "host app - - - msg"),
+        ] {
+            let header = parse(input, reference)?;
+            assert_eq!(Some(165), header.priority);
+            assert_eq!(Some(1), header.version);
+            assert_eq!(
+                *expected,
+                header.timestamp.map(|dt| dt.timestamp_nanos_opt().unwrap())
+            );
+            assert_eq!(*message, header.message);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2003-10-20T00:00:00Z")?;
+        for input in &[
+            "",
+            "<34",
+            "<192>Oct 11 22:14:15 host msg",
+            "<34>Smarch 11 22:14:15 host msg",
+            "<165>1 junk host",
+            // RFC 5424 timestamps are strictly RFC 3339
+            "<34>10 11 22:14:15 host",
+            "<165>1 1065910455 host",
+            "<165>1 2003-10-11 host",
+            "<165>1 Oct 11 22:14:15 host",
+            "<165>1 2003-10-11T22:14:15 host",
+            // versions are a non-zero digit followed by up to two digits
+            "<165>0 2003-10-11T22:14:15.003Z host",
+            "<165>01 2003-10-11T22:14:15.003Z host",
+            "<165>1000 2003-10-11T22:14:15.003Z host",
+        ] {
+            assert!(parse(input, reference).is_err(), "{input}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+//! Time parsing functions
+use crate::datetime::{normalize_fraction, parse_numeric_offset, parse_offset};
+use crate::errors::Error;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
+
+/// Attempts to parse the provided string into a `NaiveTime`.
+///
+/// Any offset or timezone abbreviation present is validated but discarded, see
+/// [`parse_with_offset`] to retain it.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `Time` format is provided.
+#[inline]
+pub fn parse(s: &str) -> Result<NaiveTime, Error> {
+    parse_with_offset(s).map(|(time, _)| time)
+}
+
+/// Attempts to parse the provided string into a `NaiveTime` and the `FixedOffset` it was
+/// specified in, if any.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `Time` format is provided.
+pub fn parse_with_offset(s: &str) -> Result<(NaiveTime, Option<FixedOffset>), Error> {
+    let s = &*normalize_fraction(s.trim());
+    // ISO 8601 time designator eg. T00:32:45Z
+    let s = match s.strip_prefix(['T', 't']) {
+        Some(t) if t.starts_with(|c: char| c.is_ascii_digit()) => t,
+        _ => s,
+    };
+    parse_naive_time(s)
+        .map(|time| (time, None))
+        .or_else(|_| parse_time_with_utc_suffix(s))
+        .or_else(|_| parse_time_with_numeric_offset(s))
+        .or_else(|_| parse_time_with_timezone_abbreviation(s))
+}
+
+/// Attempts to parse the provided time string and combine it with the provided `NaiveDate`.
+///
+/// Times without an offset or timezone abbreviation are assumed to be in UTC.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `Time` format is provided.
+pub fn parse_on(s: &str, date: NaiveDate) -> Result<DateTime<FixedOffset>, Error> {
+    let (time, offset) = parse_with_offset(s)?;
+    offset
+        .unwrap_or_else(|| Utc.fix())
+        .from_local_datetime(&date.and_time(time))
+        .single()
+        .ok_or(Error::InvalidTime)
+}
+
+pub(crate) fn parse_naive_time(s: &str) -> Result<NaiveTime, Error> {
+    // Time parse formats
+    const PARSE_FORMATS: &[&str] = &[
+        "%H:%M:%S%.f",
+        "%H:%M:%S",
+        "%H:%M",
+        "%I:%M:%S %P",
+        "%I:%M %P",
+        "%I:%M:%S%P",
+        "%I:%M%P",
+        "%Hh%M",
+        "%H时%M分%S秒",
+        "%H时%M分",
+    ];
+    PARSE_FORMATS
+        .iter()
+        .map(|fmt| NaiveTime::parse_from_str(s, fmt))
+        .find_map(Result::ok)
+        .map_or_else(|| parse_named_time(s).or_else(|_| parse_hour(s)), Ok)
+}
+
+fn parse_named_time(s: &str) -> Result<NaiveTime, Error> {
+    if s.eq_ignore_ascii_case("noon") || s.eq_ignore_ascii_case("midday") {
+        Ok(NaiveTime::from_hms_opt(12, 0, 0).unwrap_or_default())
+    } else if s.eq_ignore_ascii_case("midnight") {
+        Ok(NaiveTime::default())
+    } else {
+        Err(Error::InvalidTime)
+    }
+}
+
+// hour only times eg. 10am, 10 pm or 14h
+fn parse_hour(s: &str) -> Result<NaiveTime, Error> {
+    let lower = s.to_ascii_lowercase();
+    let (hour, offset) = if let Some(h) = lower.strip_suffix("am") {
+        (h.trim_end(), Some(0))
+    } else if let Some(h) = lower.strip_suffix("pm") {
+        (h.trim_end(), Some(12))
+    } else if let Some(h) = lower.strip_suffix('h') {
+        (h, None)
+    } else {
+        return Err(Error::InvalidTime);
+    };
+    if hour.is_empty() || hour.len() > 2 || !hour.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidTime);
+    }
+    let hour: u32 = hour.parse().map_err(|_| Error::InvalidTime)?;
+    let hour = match offset {
+        Some(offset) if (1..=12).contains(&hour) => hour % 12 + offset,
+        None => hour,
+        _ => return Err(Error::InvalidTime),
+    };
+    NaiveTime::from_hms_opt(hour, 0, 0).ok_or(Error::InvalidTime)
+}
+
+fn parse_time_with_utc_suffix(s: &str) -> Result<(NaiveTime, Option<FixedOffset>), Error> {
+    let time = s
+        .strip_suffix(['Z', 'z'])
+        .ok_or(Error::InvalidTime)?
+        .trim_end();
+    Ok((parse_naive_time(time)?, Some(Utc.fix())))
+}
+
+fn parse_time_with_numeric_offset(s: &str) -> Result<(NaiveTime, Option<FixedOffset>), Error> {
+    let idx = s.rfind(['+', '-']).ok_or(Error::InvalidTime)?;
+    let offset = parse_numeric_offset(&s[idx..]).map_err(|_| Error::InvalidTime)?;
+    Ok((parse_naive_time(s[..idx].trim_end())?, Some(offset)))
+}
+
+// timezone abbreviation can't 100% relied upon, see `datetime::parse_offset`.
+fn parse_time_with_timezone_abbreviation(
+    s: &str,
+) -> Result<(NaiveTime, Option<FixedOffset>), Error> {
+    let (time, tz) = s.rsplit_once(' ').ok_or(Error::InvalidTime)?;
+    let offset = parse_offset(tz).map_err(|_| Error::InvalidTime)?;
+    Ok((parse_naive_time(time.trim_end())?, Some(offset)))
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            // hh:mm:ss
+            ("17:24", (17, 24, 0, 0)),
+            ("17:24:37", (17, 24, 37, 0)),
+            ("17:24:37.123", (17, 24, 37, 123)),
+            ("17:24:37,123", (17, 24, 37, 123)),
+            ("00:00", (0, 0, 0, 0)),
+            // hh:mm:ss am/pm
+            ("5:57:51 PM", (17, 57, 51, 0)),
+            ("5:57:51PM", (17, 57, 51, 0)),
+            ("12:00 AM", (0, 0, 0, 0)),
+            ("10:09am", (10, 9, 0, 0)),
+            // hour only
+            ("10am", (10, 0, 0, 0)),
+            ("10 pm", (22, 0, 0, 0)),
+            ("12pm", (12, 0, 0, 0)),
+            ("12am", (0, 0, 0, 0)),
+            ("14h", (14, 0, 0, 0)),
+            ("14h30", (14, 30, 0, 0)),
+            // named
+            ("noon", (12, 0, 0, 0)),
+            ("Midnight", (0, 0, 0, 0)),
+            // chinese hh mm ss
+            ("11时25分18秒", (11, 25, 18, 0)),
+            ("11时25分", (11, 25, 0, 0)),
+        ] {
+            let (h, m, s, ms) = *expected;
+            assert_eq!(
+                NaiveTime::from_hms_milli_opt(h, m, s, ms).unwrap(),
+                parse(input)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn times_with_offset() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected, offset) in &[
+            ("17:24", (17, 24, 0, 0), None),
+            ("T00:32:45.426Z", (0, 32, 45, 426), Some(0)),
+            ("T00:32:45,426Z", (0, 32, 45, 426), Some(0)),
+            ("00:32:45Z", (0, 32, 45, 0), Some(0)),
+            ("17:24:37+08:00", (17, 24, 37, 0), Some(8 * 3600)),
+            ("17:24:37-0700", (17, 24, 37, 0), Some(-7 * 3600)),
+            ("17:24 +05", (17, 24, 0, 0), Some(5 * 3600)),
+            ("5:57 PM PDT", (17, 57, 0, 0), Some(-7 * 3600)),
+            ("10am GMT", (10, 0, 0, 0), Some(0)),
+        ] {
+            let (h, m, s, ms) = *expected;
+            let (time, parsed_offset) = parse_with_offset(input)?;
+            assert_eq!(NaiveTime::from_hms_milli_opt(h, m, s, ms).unwrap(), time);
+            assert_eq!(
+                offset.map(|o| FixedOffset::east_opt(o).unwrap()),
+                parsed_offset
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn times_on_date() -> Result<(), Box<dyn std::error::Error>> {
+        let date = NaiveDate::from_ymd_opt(2021, 11, 8).unwrap();
+        assert_eq!(1636331565, parse_on("00:32:45", date)?.timestamp());
+        assert_eq!(1636331565, parse_on("T00:32:45Z", date)?.timestamp());
+        assert_eq!(1636356765, parse_on("00:32:45 PDT", date)?.timestamp());
+        assert_eq!(1636372800, parse_on("noon", date)?.timestamp());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_times() {
+        for input in &[
+            "",
+            "25:00",
+            "13pm",
+            "0am",
+            "17:24:37+25",
+            "lunch",
+            // non-ASCII offsets
+            "17:24+aé1",
+            "時@1,\"+a日",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
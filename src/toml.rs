@@ -0,0 +1,182 @@
+//! TOML date and time parsing functions
+//!
+//! Implements the exact TOML v1.0.0 grammar, which unlike [`crate::datetime::parse`] preserves
+//! whether a value has an offset, a date or a time:
+//!
+//! - offset date-time eg. `1979-05-27T07:32:00Z` or `1979-05-27 00:32:00-07:00`
+//! - local date-time eg. `1979-05-27T07:32:00`
+//! - local date eg. `1979-05-27`
+//! - local time eg. `07:32:00.999`
+//!
+//! Fractional seconds beyond nanosecond precision are truncated.
+use crate::errors::Error;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+
+/// A parsed TOML date or time value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    /// An offset date-time eg. `1979-05-27T00:32:00-07:00`.
+    OffsetDateTime(DateTime<FixedOffset>),
+    /// A date-time without an offset eg. `1979-05-27T07:32:00`.
+    LocalDateTime(NaiveDateTime),
+    /// A date without a time eg. `1979-05-27`.
+    LocalDate(NaiveDate),
+    /// A time without a date eg. `07:32:00.999`.
+    LocalTime(NaiveTime),
+}
+
+/// Attempts to parse the provided TOML date or time string, preserving which kind it was.
+///
+/// # Errors
+/// Will return `Err` when the string does not exactly match one of the TOML date or time kinds.
+pub fn parse(s: &str) -> Result<Value, Error> {
+    parse_value(s.as_bytes()).ok_or(Error::InvalidDateTime)
+}
+
+fn parse_value(b: &[u8]) -> Option<Value> {
+    if b.get(2) == Some(&b':') {
+        let (time, idx) = parse_time(b, 0)?;
+        return (idx == b.len()).then_some(Value::LocalTime(time));
+    }
+
+    let (year, idx) = digits(b, 0, 4)?;
+    let (month, idx) = digits(b, byte(b, idx, b"-")?, 2)?;
+    let (day, idx) = digits(b, byte(b, idx, b"-")?, 2)?;
+    let date = NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)?;
+    if idx == b.len() {
+        return Some(Value::LocalDate(date));
+    }
+
+    // the delimiter may be a space rather than `T` for readability
+    let (time, idx) = parse_time(b, byte(b, idx, b"Tt ")?)?;
+    let ndt = date.and_time(time);
+    let offset = match b.get(idx) {
+        None => return Some(Value::LocalDateTime(ndt)),
+        Some(b'Z' | b'z') if idx + 1 == b.len() => Utc.fix(),
+        Some(sign @ (b'+' | b'-')) => {
+            let (hours, idx) = digits(b, idx + 1, 2)?;
+            let (minutes, idx) = digits(b, byte(b, idx, b":")?, 2)?;
+            if idx != b.len() || hours > 23 || minutes > 59 {
+                return None;
+            }
+            let secs = i32::try_from(hours * 3600 + minutes * 60).ok()?;
+            FixedOffset::east_opt(if *sign == b'-' { -secs } else { secs })?
+        }
+        _ => return None,
+    };
+    offset
+        .from_local_datetime(&ndt)
+        .single()
+        .map(Value::OffsetDateTime)
+}
+
+// eg. `07:32:00` or `07:32:00.999999`, seconds are required.
+fn parse_time(b: &[u8], idx: usize) -> Option<(NaiveTime, usize)> {
+    let (hour, idx) = digits(b, idx, 2)?;
+    let (minute, idx) = digits(b, byte(b, idx, b":")?, 2)?;
+    let (second, mut idx) = digits(b, byte(b, idx, b":")?, 2)?;
+    let mut nanos = 0;
+    if let Some(start) = byte(b, idx, b".") {
+        idx = start + b[start..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if idx == start {
+            return None;
+        }
+        // truncate to nanoseconds
+        nanos = (start..start + 9).fold(0, |nanos, i| {
+            nanos * 10
+                + b.get(i)
+                    .filter(|_| i < idx)
+                    .map_or(0, |d| u32::from(d - b'0'))
+        });
+    }
+    Some((
+        NaiveTime::from_hms_nano_opt(hour, minute, second, nanos)?,
+        idx,
+    ))
+}
+
+// parses exactly `n` ASCII digits starting at `idx`, returning the value and the index after.
+fn digits(b: &[u8], idx: usize, n: usize) -> Option<(u32, usize)> {
+    let value = b.get(idx..idx + n)?.iter().try_fold(0, |value, d| {
+        d.is_ascii_digit().then(|| value * 10 + u32::from(d - b'0'))
+    })?;
+    Some((value, idx + n))
+}
+
+fn byte(b: &[u8], idx: usize, expected: &[u8]) -> Option<usize> {
+    b.get(idx).filter(|c| expected.contains(c)).map(|_| idx + 1)
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_date_times() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            ("1979-05-27T07:32:00Z", 296638320000000000),
+            ("1979-05-27T00:32:00-07:00", 296638320000000000),
+            ("1979-05-27T00:32:00.999999-07:00", 296638320999999000),
+            ("1979-05-27 07:32:00Z", 296638320000000000),
+            ("1979-05-27t07:32:00z", 296638320000000000),
+        ] {
+            match parse(input)? {
+                Value::OffsetDateTime(dt) => {
+                    assert_eq!(*expected, dt.timestamp_nanos_opt().unwrap(), "{input}");
+                }
+                value => panic!("{input} parsed as {value:?}"),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn local() -> Result<(), Box<dyn std::error::Error>> {
+        let date = NaiveDate::from_ymd_opt(1979, 5, 27).unwrap();
+        let time = NaiveTime::from_hms_opt(7, 32, 0).unwrap();
+        for (input, expected) in [
+            (
+                "1979-05-27T07:32:00",
+                Value::LocalDateTime(date.and_time(time)),
+            ),
+            (
+                "1979-05-27 07:32:00",
+                Value::LocalDateTime(date.and_time(time)),
+            ),
+            (
+                "1979-05-27T00:32:00.999999",
+                Value::LocalDateTime(date.and_hms_micro_opt(0, 32, 0, 999999).unwrap()),
+            ),
+            ("1979-05-27", Value::LocalDate(date)),
+            ("07:32:00", Value::LocalTime(time)),
+            (
+                "00:32:00.1234567891",
+                Value::LocalTime(NaiveTime::from_hms_nano_opt(0, 32, 0, 123456789).unwrap()),
+            ),
+        ] {
+            assert_eq!(expected, parse(input)?, "{input}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "1979-5-27",
+            "79-05-27",
+            "1979-05-27T07:32Z",
+            "1979-05-27  07:32:00",
+            "1979-05-27T07:32:00+07",
+            "1979-05-27T07:32:00+0700",
+            "1979-05-27T07:32:00.Z",
+            "1979-05-27T07:32:00 Z",
+            "1979-05-27T24:00:00",
+            "07:32",
+            "7:32:00",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
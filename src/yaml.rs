@@ -0,0 +1,144 @@
+//! YAML timestamp parsing functions
+//!
+//! Implements the YAML 1.1 `timestamp` type, which is more lenient than RFC 3339:
+//!
+//! - a lowercase `t` or any spaces and tabs between the date and time eg.
+//!   `2001-12-14t21:59:43.10-05:00` or `2001-12-14 21:59:43.10 -5`
+//! - single digit months, days, hours and offset hours eg. `2001-1-2 3:04:05 +1`
+//! - a date on its own eg. `2002-12-14`, which is midnight UTC
+//!
+//! Timestamps without a zone are also in UTC.
+use crate::errors::Error;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
+
+/// Attempts to parse the provided YAML 1.1 timestamp into a `DateTime`\<`FixedOffset`\>.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported timestamp is provided.
+pub fn parse(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    parse_timestamp(s.as_bytes()).ok_or(Error::InvalidDateTime)
+}
+
+fn parse_timestamp(b: &[u8]) -> Option<DateTime<FixedOffset>> {
+    let (year, idx) = digits(b, 0, 4..=4)?;
+    let (month, idx) = digits(b, byte(b, idx, b"-")?, 1..=2)?;
+    let (day, idx) = digits(b, byte(b, idx, b"-")?, 1..=2)?;
+    let date = NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)?;
+    if idx == b.len() {
+        // a date on its own requires two digit months and days
+        return (idx == 10).then(|| date.and_time(NaiveTime::MIN).and_utc().fixed_offset());
+    }
+
+    let idx = byte(b, idx, b"Tt").or_else(|| Some(blanks(b, idx)).filter(|i| *i > idx))?;
+    let (hour, idx) = digits(b, idx, 1..=2)?;
+    let (minute, idx) = digits(b, byte(b, idx, b":")?, 2..=2)?;
+    let (second, mut idx) = digits(b, byte(b, idx, b":")?, 2..=2)?;
+    let mut nanos = 0;
+    if let Some(start) = byte(b, idx, b".") {
+        idx = start + b[start..].iter().take_while(|c| c.is_ascii_digit()).count();
+        // truncate to nanoseconds
+        nanos = (start..start + 9).fold(0, |nanos, i| {
+            nanos * 10
+                + b.get(i)
+                    .filter(|_| i < idx)
+                    .map_or(0, |d| u32::from(d - b'0'))
+        });
+    }
+    let time = NaiveTime::from_hms_nano_opt(hour, minute, second, nanos)?;
+
+    let zone = blanks(b, idx);
+    let offset = match b.get(zone) {
+        None if zone == idx => Utc.fix(),
+        Some(b'Z') if zone + 1 == b.len() => Utc.fix(),
+        Some(sign @ (b'+' | b'-')) => {
+            let (hours, idx) = digits(b, zone + 1, 1..=2)?;
+            let (minutes, idx) = match byte(b, idx, b":") {
+                Some(idx) => digits(b, idx, 2..=2)?,
+                None => (0, idx),
+            };
+            if idx != b.len() || minutes > 59 {
+                return None;
+            }
+            let secs = i32::try_from(hours * 3600 + minutes * 60).ok()?;
+            FixedOffset::east_opt(if *sign == b'-' { -secs } else { secs })?
+        }
+        _ => return None,
+    };
+    offset.from_local_datetime(&date.and_time(time)).single()
+}
+
+// parses between `n` ASCII digits starting at `idx`, returning the value and the index after.
+fn digits(b: &[u8], idx: usize, n: std::ops::RangeInclusive<usize>) -> Option<(u32, usize)> {
+    let len = b
+        .get(idx..)?
+        .iter()
+        .take(*n.end())
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    if !n.contains(&len) {
+        return None;
+    }
+    let value = b[idx..idx + len]
+        .iter()
+        .fold(0, |value, d| value * 10 + u32::from(d - b'0'));
+    Some((value, idx + len))
+}
+
+fn byte(b: &[u8], idx: usize, expected: &[u8]) -> Option<usize> {
+    b.get(idx).filter(|c| expected.contains(c)).map(|_| idx + 1)
+}
+
+// returns the index after any spaces and tabs starting at `idx`.
+fn blanks(b: &[u8], idx: usize) -> usize {
+    idx + b[idx..]
+        .iter()
+        .take_while(|c| matches!(c, b' ' | b'\t'))
+        .count()
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps() -> Result<(), Box<dyn std::error::Error>> {
+        for (input, expected) in &[
+            // examples from the YAML 1.1 timestamp type
+            ("2001-12-15T02:59:43.1Z", 1008385183100000000),
+            ("2001-12-14t21:59:43.10-05:00", 1008385183100000000),
+            ("2001-12-14 21:59:43.10 -5", 1008385183100000000),
+            ("2001-12-15 2:59:43.10", 1008385183100000000),
+            ("2002-12-14", 1039824000000000000),
+            // single digits, tabs and unusual fractions
+            ("2001-1-2 3:04:05 +1", 978401045000000000),
+            ("2001-12-14\t \t21:59:43\tZ", 1008367183000000000),
+            ("2001-12-14t21:59:43.-05:30", 1008386983000000000),
+            ("2001-12-15T02:59:43.1234567891Z", 1008385183123456789),
+        ] {
+            assert_eq!(
+                *expected,
+                parse(input)?.timestamp_nanos_opt().unwrap(),
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        for input in &[
+            "",
+            "2001-1-2",
+            "01-12-14",
+            "2001-12-14T21:59",
+            "2001-12-14 21:59:43 ",
+            "2001-12-14T21:59:43+5:0",
+            "2001-12-14T21:59:43+123",
+            "2001-12-14T21:59:43 PST",
+            "2001-13-14T21:59:43Z",
+        ] {
+            assert!(parse(input).is_err(), "{input}");
+        }
+    }
+}
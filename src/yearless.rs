@@ -0,0 +1,206 @@
+//! Year-less `Date` & `DateTime` parsing functions
+//!
+//! Formats such as syslog's `Nov  8 00:32:45` or bank statements' `08 Nov` omit the year, which
+//! is instead inferred from a reference date using a [`YearPolicy`].
+use crate::errors::Error;
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc,
+};
+
+// leap year used to validate year-less dates, allowing Feb 29th, before the year is inferred.
+pub(crate) const LEAP_YEAR: i32 = 2000;
+
+/// Determines which year a year-less date resolves to relative to the reference date.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YearPolicy {
+    /// The occurrence closest to the reference, whether in the past or future.
+    #[default]
+    Nearest,
+    /// The most recent occurrence at or before the reference.
+    Past,
+    /// The next occurrence at or after the reference.
+    Future,
+}
+
+/// Attempts to parse the provided year-less string into a `DateTime`\<`FixedOffset`\>, inferring
+/// the year from the `reference` according to the `policy`.
+///
+/// `DateTime`s without an offset or timezone abbreviation are assumed to be in UTC.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `DateTime` format is provided.
+pub fn parse(
+    s: &str,
+    reference: DateTime<FixedOffset>,
+    policy: YearPolicy,
+) -> Result<DateTime<FixedOffset>, Error> {
+    let (ndt, offset) = parse_naive(s)?;
+    resolve(ndt, offset.unwrap_or_else(|| Utc.fix()), reference, policy)
+}
+
+/// Attempts to parse the provided year-less string into a `NaiveDate`, inferring the year from
+/// the `reference` according to the `policy`.
+///
+/// # Errors
+/// Will return `Err` when an invalid or unsupported `Date` format is provided.
+pub fn parse_date(s: &str, reference: NaiveDate, policy: YearPolicy) -> Result<NaiveDate, Error> {
+    let date = parse_naive_date(s.trim())?;
+    let reference = Utc
+        .fix()
+        .from_utc_datetime(&reference.and_time(NaiveTime::default()));
+    resolve(
+        date.and_time(NaiveTime::default()),
+        Utc.fix(),
+        reference,
+        policy,
+    )
+    .map(|dt| dt.date_naive())
+    .map_err(|_| Error::InvalidDate)
+}
+
+/// Parses a year-less `DateTime` into a `NaiveDateTime` with a placeholder year, to be
+/// [`resolve`]d, along with its offset if specified.
+pub(crate) fn parse_naive(s: &str) -> Result<(NaiveDateTime, Option<FixedOffset>), Error> {
+    let s = s.trim();
+    s.char_indices()
+        .filter(|(_, c)| c.is_whitespace())
+        .find_map(|(idx, _)| {
+            let date = parse_naive_date(s[..idx].trim_end_matches(',')).ok()?;
+            let (time, offset) = crate::time::parse_with_offset(&s[idx..]).ok()?;
+            Some((date.and_time(time), offset))
+        })
+        .ok_or(Error::InvalidDateTime)
+}
+
+/// Resolves a `NaiveDateTime`, whose year is ignored, in the provided `offset` to the year
+/// selected by the `policy` relative to the `reference`.
+///
+/// # Errors
+/// Will return `Err` when the month and day don't occur in any nearby year, eg. Feb 29th with a
+/// `reference` far from a leap year.
+pub(crate) fn resolve(
+    ndt: NaiveDateTime,
+    offset: FixedOffset,
+    reference: DateTime<FixedOffset>,
+    policy: YearPolicy,
+) -> Result<DateTime<FixedOffset>, Error> {
+    let year = reference.with_timezone(&offset).year();
+    // wide enough to always include a leap year on either side of the reference for Feb 29th
+    (year - 8..=year + 8)
+        .filter_map(|y| ndt.with_year(y))
+        .filter_map(|ndt| offset.from_local_datetime(&ndt).single())
+        .filter(|dt| match policy {
+            YearPolicy::Nearest => true,
+            YearPolicy::Past => *dt <= reference,
+            YearPolicy::Future => *dt >= reference,
+        })
+        .min_by_key(|dt| dt.signed_duration_since(reference).abs())
+        .ok_or(Error::InvalidDateTime)
+}
+
+// parses a year-less date, assigning it the `LEAP_YEAR`.
+fn parse_naive_date(s: &str) -> Result<NaiveDate, Error> {
+    // Date parse formats, prefixed with the assigned year
+    const PARSE_FORMATS: &[&str] = &["%Y %b %e", "%Y %e %b", "%Y %B %e", "%Y %e %B"];
+    let s = format!("{LEAP_YEAR} {}", s.replace([',', '.'], ""));
+    PARSE_FORMATS
+        .iter()
+        .map(|fmt| NaiveDate::parse_from_str(&s, fmt))
+        .find_map(Result::ok)
+        .map_or_else(|| Err(Error::InvalidDate), Ok)
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetimes() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2021-11-10T12:00:00Z")?;
+        for (input, expected) in &[
+            // syslog
+            ("Nov  8 00:32:45", 1636331565000000000),
+            ("Nov 8 00:32:45.426", 1636331565426000000),
+            ("Nov 8 00:32:45,426", 1636331565426000000),
+            // chat exports
+            ("Nov 8, 5:57 PM", 1636394220000000000),
+            ("November 8, 5:57:51 pm", 1636394271000000000),
+            // dd Mon hh:mm
+            ("08 Nov 00:32", 1636331520000000000),
+            // timezones
+            ("Nov 8 00:32:45 PDT", 1636356765000000000),
+            ("Nov 8 00:32:45 -0700", 1636356765000000000),
+        ] {
+            assert_eq!(
+                *expected,
+                parse(input, reference, YearPolicy::Nearest)?
+                    .timestamp_nanos_opt()
+                    .unwrap()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn year_policies() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = crate::datetime::parse("2022-01-01T00:10:00Z")?;
+        for (input, policy, expected) in &[
+            // spanning new year
+            (
+                "Dec 31 23:59:59",
+                YearPolicy::Nearest,
+                "2021-12-31T23:59:59Z",
+            ),
+            (
+                "Jan 1 00:00:01",
+                YearPolicy::Nearest,
+                "2022-01-01T00:00:01Z",
+            ),
+            ("Dec 31 23:59:59", YearPolicy::Past, "2021-12-31T23:59:59Z"),
+            ("Jan 1 00:20:00", YearPolicy::Past, "2021-01-01T00:20:00Z"),
+            (
+                "Dec 31 23:59:59",
+                YearPolicy::Future,
+                "2022-12-31T23:59:59Z",
+            ),
+            ("Jan 1 00:20:00", YearPolicy::Future, "2022-01-01T00:20:00Z"),
+            // leap day
+            ("Feb 29 12:00", YearPolicy::Nearest, "2020-02-29T12:00:00Z"),
+            ("Feb 29 12:00", YearPolicy::Future, "2024-02-29T12:00:00Z"),
+        ] {
+            assert_eq!(
+                crate::datetime::parse(expected)?,
+                parse(input, reference, *policy)?,
+                "{input}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dates() -> Result<(), Box<dyn std::error::Error>> {
+        let reference = NaiveDate::from_ymd_opt(2021, 1, 5).unwrap();
+        for (input, policy, expected) in &[
+            ("08 Nov", YearPolicy::Nearest, (2020, 11, 8)),
+            ("Nov 8", YearPolicy::Future, (2021, 11, 8)),
+            ("january 5", YearPolicy::Past, (2021, 1, 5)),
+            ("5 Jan.", YearPolicy::Future, (2021, 1, 5)),
+        ] {
+            let (y, m, d) = *expected;
+            assert_eq!(
+                NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+                parse_date(input, reference, *policy)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        let reference = NaiveDate::from_ymd_opt(2021, 1, 5).unwrap();
+        for input in &["", "Nov 31", "Smarch 8", "2021-11-08"] {
+            assert!(parse_date(input, reference, YearPolicy::Nearest).is_err());
+        }
+    }
+}